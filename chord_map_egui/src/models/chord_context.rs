@@ -1,12 +1,14 @@
 use std::fmt::{Display, Formatter};
 
-use note_lib::{Chord, ChordQuality, Note};
+use note_lib::{AbstractNote, Chord, ChordQuality, Note};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ChordContext {
     root: Note,
     quality: ChordQuality,
 
+    #[serde(skip)]
     calculated_chord: Option<Chord>,
 }
 
@@ -38,13 +40,12 @@ impl ChordContext {
     }
 
     pub fn get_calculated_chord(&mut self) -> &Chord {
-        if self.calculated_chord.is_some() {
-            self.calculated_chord.as_ref().unwrap()
-        } else {
+        if self.calculated_chord.is_none() {
             let chord = self._calculate_chord();
             self.calculated_chord.replace(chord);
-            self.calculated_chord.as_ref().unwrap()
         }
+
+        self.calculated_chord.as_ref().unwrap()
     }
 
     fn _calculate_chord(&self) -> Chord {
@@ -52,8 +53,58 @@ impl ChordContext {
     }
 }
 
+/// Names the chord by the root and quality it was built from, since some
+/// qualities share notes with others, like a German 6th and a dominant 7th.
 impl Display for ChordContext {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#} {}", self.root, self.quality.short_name())
+        let calculated_chord;
+        let chord = match &self.calculated_chord {
+            Some(chord) => chord,
+            None => {
+                calculated_chord = self._calculate_chord();
+                &calculated_chord
+            }
+        };
+        let notes = chord
+            .notes()
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(
+            f,
+            "{} {:#} ({})",
+            AbstractNote::from(self.root),
+            self.quality,
+            notes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use note_lib::{NoteModifier, A};
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn names_every_pickable_quality() {
+        let root = Note::new(A, 4, NoteModifier::Flat);
+
+        for quality in ChordQuality::iter().filter(|q| *q != ChordQuality::Neapolitan) {
+            let heading = ChordContext::new(root, quality).to_string();
+            assert!(
+                heading.starts_with(&format!("Ab {:#} (", quality)),
+                "{quality:?} was shown as {heading}"
+            );
+        }
+
+        assert_eq!(
+            ChordContext::new(root, ChordQuality::German6).to_string(),
+            "Ab German Augmented 6th (Ab4, C5, Eb5, F#5)"
+        );
     }
 }
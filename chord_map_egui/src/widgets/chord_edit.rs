@@ -1,5 +1,5 @@
 use egui::{ComboBox, DragValue, Widget, WidgetText};
-use note_lib::{Note, NoteModifier, RawNote};
+use note_lib::{ChordQuality, Note, NoteModifier, Octave, RawNote};
 use strum::IntoEnumIterator;
 
 use crate::models::chord_context::ChordContext;
@@ -18,13 +18,13 @@ impl RawNoteOption {
 
 impl From<RawNoteOption> for WidgetText {
     fn from(val: RawNoteOption) -> Self {
-        WidgetText::RichText(format!("{}{}", val.note, val.modifier).into())
+        WidgetText::RichText(format!("{:#}", val.note + val.modifier).into())
     }
 }
 
 impl From<&RawNoteOption> for WidgetText {
     fn from(val: &RawNoteOption) -> Self {
-        WidgetText::RichText(format!("{}{}", val.note, val.modifier).into())
+        WidgetText::RichText(format!("{:#}", val.note + val.modifier).into())
     }
 }
 pub enum ChordEditAction {
@@ -34,7 +34,8 @@ pub enum ChordEditAction {
 
 pub fn chord_edit(ui: &mut egui::Ui, chord_edit_ctx: &mut ChordContext) -> Option<ChordEditAction> {
     let current_root = chord_edit_ctx.get_root();
-    let mut current_octave = current_root.octave();
+    let mut current_octave = current_root.octave().value();
+    let mut current_quality = chord_edit_ctx.get_quality();
     let mut current_root_and_modifier: RawNoteOption =
         RawNoteOption::new(current_root.raw_note(), current_root.modifier());
 
@@ -60,9 +61,23 @@ pub fn chord_edit(ui: &mut egui::Ui, chord_edit_ctx: &mut ChordContext) -> Optio
                     .reduce(|a, b| a.union(b))
             });
 
+        ComboBox::new("Quality", "")
+            .width(70.0)
+            .selected_text(current_quality.short_name())
+            .show_ui(ui, |ui| {
+                // The Neapolitan is built from the tonic, so it gives a chord
+                // whose root isn't the picked root, like a Db triad on C.
+                ChordQuality::iter()
+                    .filter(|quality| *quality != ChordQuality::Neapolitan)
+                    .map(|quality| {
+                        ui.selectable_value(&mut current_quality, quality, quality.short_name())
+                    })
+                    .reduce(|a, b| a.union(b))
+            });
+
         let octave_drag_box = DragValue::new(&mut current_octave)
             .speed(0.05)
-            .clamp_range(0..=10)
+            .clamp_range(Octave::MIN..=Octave::MAX)
             .ui(ui);
 
         root_combo
@@ -88,6 +103,10 @@ pub fn chord_edit(ui: &mut egui::Ui, chord_edit_ctx: &mut ChordContext) -> Optio
         })
     });
 
+    if current_quality != chord_edit_ctx.get_quality() {
+        chord_edit_ctx.set_quality(current_quality);
+    }
+
     if root_or_octave_changed.inner {
         let RawNoteOption { note, modifier } = current_root_and_modifier;
         chord_edit_ctx.set_root(Note::new(note, current_octave, modifier))
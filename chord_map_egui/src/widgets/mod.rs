@@ -1,5 +1,9 @@
 mod chord_edit;
 mod chord_view;
+mod piano_keyboard;
+mod scale_degree_chord_map;
 
 pub use chord_edit::*;
 pub use chord_view::*;
+pub use piano_keyboard::*;
+pub use scale_degree_chord_map::*;
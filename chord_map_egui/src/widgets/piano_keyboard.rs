@@ -0,0 +1,76 @@
+use std::collections::BTreeSet;
+
+use egui::{Color32, Rect, Response, Sense, Stroke, Ui, Vec2};
+
+pub const WHITE_KEY_SIZE: Vec2 = Vec2::new(20.0, 80.0);
+pub const BLACK_KEY_SIZE: Vec2 = Vec2::new(12.0, 50.0);
+
+/// The pitch classes of the white keys, from C up to B.
+const WHITE_KEYS: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+/// The pitch classes of the black keys, with the index of the white key each
+/// one sits to the right of.
+const BLACK_KEYS: [(u8, usize); 5] = [(1, 0), (3, 1), (6, 3), (8, 4), (10, 5)];
+
+/// Draws a one octave piano keyboard from C to B, highlighting the keys whose
+/// pitch class (0 for C through 11 for B) is in `pitch_classes`.
+pub fn piano_keyboard_widget(ui: &mut Ui, pitch_classes: &BTreeSet<u8>) -> Response {
+    let size = Vec2::new(WHITE_KEY_SIZE.x * WHITE_KEYS.len() as f32, WHITE_KEY_SIZE.y);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let painter = ui.painter_at(rect);
+    let highlight = ui.visuals().selection.bg_fill;
+    let stroke = Stroke::new(1.0, Color32::DARK_GRAY);
+
+    let fill = |pitch_class: u8, unselected: Color32| {
+        if pitch_classes.contains(&pitch_class) {
+            highlight
+        } else {
+            unselected
+        }
+    };
+
+    for (index, pitch_class) in WHITE_KEYS.iter().enumerate() {
+        let min = rect.min + Vec2::new(WHITE_KEY_SIZE.x * index as f32, 0.0);
+        let key_rect = Rect::from_min_size(min, WHITE_KEY_SIZE);
+        painter.rect(key_rect, 2.0, fill(*pitch_class, Color32::WHITE), stroke);
+    }
+
+    // Black keys are drawn last so they sit on top of the white keys.
+    for (pitch_class, white_key_index) in BLACK_KEYS {
+        let center_x = rect.min.x + WHITE_KEY_SIZE.x * (white_key_index + 1) as f32;
+        let min = egui::pos2(center_x - BLACK_KEY_SIZE.x / 2.0, rect.min.y);
+        let key_rect = Rect::from_min_size(min, BLACK_KEY_SIZE);
+        painter.rect(key_rect, 2.0, fill(pitch_class, Color32::BLACK), stroke);
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+
+    use egui::{CentralPanel, Context, RawInput};
+
+    use super::*;
+
+    fn draw(pitch_classes: &BTreeSet<u8>) {
+        let ctx = Context::default();
+        let _ = ctx.run(RawInput::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| piano_keyboard_widget(ui, pitch_classes));
+        });
+    }
+
+    #[test]
+    fn draws_without_pitch_classes() {
+        draw(&BTreeSet::new());
+    }
+
+    #[test]
+    fn draws_every_pitch_class() {
+        draw(&(0..12).collect());
+    }
+}
@@ -0,0 +1,41 @@
+use egui::{Color32, RichText, Ui};
+use note_lib::{ChordQuality, Key, ScaleDegree};
+
+pub enum ScaleDegreeChordMapAction {
+    ScaleDegreeSelected(ScaleDegree),
+}
+
+fn quality_color(ui: &Ui, quality: Option<ChordQuality>) -> Color32 {
+    match quality {
+        Some(ChordQuality::Minor) => Color32::LIGHT_BLUE,
+        Some(ChordQuality::Diminished) => Color32::LIGHT_RED,
+        Some(ChordQuality::Augmented) => Color32::LIGHT_YELLOW,
+        _ => ui.visuals().text_color(),
+    }
+}
+
+pub fn scale_degree_chord_map(ui: &mut Ui, key: &Key) -> Option<ScaleDegreeChordMapAction> {
+    ui.horizontal(|ui| {
+        key.diatonic_chords()
+            .into_iter()
+            .map(|(degree, chord)| {
+                let identified = chord.identify_quality();
+                let quality = identified.map(|(_, quality)| quality);
+
+                let label = match identified {
+                    Some((root, quality)) => {
+                        format!("{}\n{:#}{}", degree.chord_numeral(quality), root, quality)
+                    }
+                    None => degree.to_string(),
+                };
+
+                let text = RichText::new(label).color(quality_color(ui, quality));
+
+                ui.button(text)
+                    .clicked()
+                    .then_some(ScaleDegreeChordMapAction::ScaleDegreeSelected(degree))
+            })
+            .fold(None, |selected, action| selected.or(action))
+    })
+    .inner
+}
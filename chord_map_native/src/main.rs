@@ -1,7 +1,13 @@
 mod models;
 mod ui;
 
-use models::chord_map_state::ChordMapState;
+use std::path::Path;
+
+use eframe::egui::{Event, Key, Modifiers};
+use models::chord_map_state::{ChordMapLoadError, ChordMapState};
+
+/// Where the chord map is saved to, and loaded from on startup.
+const SAVE_FILE_PATH: &str = "chord_map.json";
 
 fn main() -> Result<(), eframe::Error> {
     env_logger::init();
@@ -20,10 +26,70 @@ fn main() -> Result<(), eframe::Error> {
 #[derive(Debug, Default)]
 struct ChordMapApp {
     chord_map_state: ChordMapState,
+    loaded: bool,
+}
+
+impl ChordMapApp {
+    fn load_chord_map(&mut self) {
+        match ChordMapState::load(Path::new(SAVE_FILE_PATH)) {
+            Ok(state) => self.chord_map_state = state,
+            // Nothing has been saved yet.
+            Err(ChordMapLoadError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => (),
+            Err(e) => log::warn!("{}", e),
+        }
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &eframe::egui::Context) {
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::S)) {
+            self.save_chord_map();
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::N)) {
+            self.chord_map_state.add_chord(ctx.screen_rect().center());
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            self.chord_map_state.close_edit_windows();
+        }
+
+        // Leave deleting and copying text to whichever widget is being typed in.
+        if ctx.wants_keyboard_input() {
+            return;
+        }
+
+        let delete_pressed = ctx.input_mut(|i| {
+            i.consume_key(Modifiers::NONE, Key::Delete)
+                || i.consume_key(Modifiers::NONE, Key::Backspace)
+        });
+        if delete_pressed {
+            self.chord_map_state.remove_last_chord();
+        }
+
+        // The integration turns Ctrl+C into a copy event rather than a key press.
+        if ctx.input(|i| i.events.iter().any(|event| matches!(event, Event::Copy))) {
+            let text = self.chord_map_state.chords_as_text();
+            ctx.output_mut(|o| o.copied_text = text);
+        }
+    }
+
+    fn save_chord_map(&self) {
+        if let Err(e) = self.chord_map_state.save(Path::new(SAVE_FILE_PATH)) {
+            log::error!("{}", e);
+        }
+    }
 }
 
 impl eframe::App for ChordMapApp {
     fn update(&mut self, ctx: &eframe::egui::Context, _frame: &mut eframe::Frame) {
+        if !self.loaded {
+            self.load_chord_map();
+            self.loaded = true;
+        }
+
+        self.handle_shortcuts(ctx);
+
+        // Side panels have to be shown before the central panel.
+        ui::static_ui::chord_keyboard_panel(ctx, &mut self.chord_map_state);
         ui::static_ui::main_ui(ctx, &mut self.chord_map_state);
         ui::static_ui::chords_edit_windows(ctx, &mut self.chord_map_state);
         ui::static_ui::chords_display(ctx, &mut self.chord_map_state);
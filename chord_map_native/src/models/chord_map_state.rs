@@ -1,12 +1,206 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs::File,
+    io::BufReader,
+    path::Path,
+};
+
+use eframe::epaint::Pos2;
+use note_lib::{ChordQuality, Note, NoteModifier, C};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
 use super::chord_view_context::ChordViewContext;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ChordMapState {
-    pub delete_chord: Option<ChordViewContext>,
     pub chord_views: Vec<ChordViewContext>,
+    /// The chord last added or moved on the map.
+    #[serde(skip)]
+    pub selected_chord: Option<Uuid>,
 
     /// Map X offset from center
     pub map_x: f64,
     /// Map Y offset from center
     pub map_y: f64,
 }
+
+#[derive(Debug)]
+pub enum ChordMapSaveError {
+    Io(std::io::Error),
+    Serialize(serde_json::Error),
+}
+
+#[derive(Debug)]
+pub enum ChordMapLoadError {
+    Io(std::io::Error),
+    Deserialize(serde_json::Error),
+}
+
+impl Display for ChordMapSaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordMapSaveError::Io(e) => write!(f, "could not write the chord map file: {}", e),
+            ChordMapSaveError::Serialize(e) => {
+                write!(f, "could not serialize the chord map: {}", e)
+            }
+        }
+    }
+}
+
+impl Display for ChordMapLoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChordMapLoadError::Io(e) => write!(f, "could not read the chord map file: {}", e),
+            ChordMapLoadError::Deserialize(e) => {
+                write!(f, "could not deserialize the chord map: {}", e)
+            }
+        }
+    }
+}
+
+impl ChordMapState {
+    /// Adds a C major chord at `position` with its edit window open, and
+    /// selects it.
+    pub fn add_chord(&mut self, position: Pos2) {
+        let mut chord_view =
+            ChordViewContext::new(Note::new(C, 4, NoteModifier::Natural), ChordQuality::Major);
+        chord_view.window_open = true;
+        chord_view.set_position(position);
+
+        self.selected_chord.replace(chord_view.id());
+        self.chord_views.push(chord_view);
+    }
+
+    /// Removes the chord that was added most recently.
+    pub fn remove_last_chord(&mut self) {
+        if let Some(removed) = self.chord_views.pop() {
+            if self.selected_chord == Some(removed.id()) {
+                self.selected_chord = None;
+            }
+        }
+    }
+
+    /// Closes every open edit window, dropping any changes that weren't
+    /// committed.
+    pub fn close_edit_windows(&mut self) {
+        for chord_view in self.chord_views.iter_mut() {
+            chord_view.window_open = false;
+            chord_view.editing_chord_context = None;
+        }
+    }
+
+    /// Lists the chords on the map as text, one chord per line, like
+    /// `C4 Major: C4 E4 G4`.
+    pub fn chords_as_text(&self) -> String {
+        self.chord_views
+            .iter()
+            .map(|chord_view| {
+                let root = chord_view.chord_context.get_root();
+                let quality = chord_view.chord_context.get_quality();
+                let notes: Vec<String> = quality
+                    .to_notes(root)
+                    .iter()
+                    .map(|note| note.to_string())
+                    .collect();
+
+                format!("{} {:#}: {}", root, quality, notes.join(" "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Saves the chords on the map, with their positions and whether their
+    /// edit windows are open, to a JSON file.
+    pub fn save(&self, path: &Path) -> Result<(), ChordMapSaveError> {
+        let file = File::create(path).map_err(ChordMapSaveError::Io)?;
+        serde_json::to_writer_pretty(file, self).map_err(ChordMapSaveError::Serialize)
+    }
+
+    /// Loads a chord map previously written by [`ChordMapState::save`].
+    pub fn load(path: &Path) -> Result<ChordMapState, ChordMapLoadError> {
+        let file = File::open(path).map_err(ChordMapLoadError::Io)?;
+        serde_json::from_reader(BufReader::new(file)).map_err(ChordMapLoadError::Deserialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use note_lib::A;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let mut first =
+            ChordViewContext::new(Note::new(C, 4, NoteModifier::Natural), ChordQuality::Major);
+        first.set_position(Pos2::new(10.0, 20.0));
+
+        let mut second =
+            ChordViewContext::new(Note::new(A, 3, NoteModifier::Flat), ChordQuality::Minor7th);
+        second.set_position(Pos2::new(-5.5, 42.0));
+        second.window_open = true;
+
+        let state = ChordMapState {
+            chord_views: vec![first, second],
+            map_x: 1.5,
+            map_y: -3.0,
+            ..Default::default()
+        };
+
+        let path = std::env::temp_dir().join(format!("chord_map_{}.json", uuid::Uuid::new_v4()));
+        state.save(&path).unwrap();
+        let loaded = ChordMapState::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.map_x, state.map_x);
+        assert_eq!(loaded.map_y, state.map_y);
+        assert_eq!(loaded.chord_views.len(), state.chord_views.len());
+
+        for (loaded, saved) in loaded.chord_views.iter().zip(state.chord_views.iter()) {
+            assert_eq!(loaded.id(), saved.id());
+            assert_eq!(
+                loaded.chord_context.get_root(),
+                saved.chord_context.get_root()
+            );
+            assert_eq!(
+                loaded.chord_context.get_quality(),
+                saved.chord_context.get_quality()
+            );
+            assert_eq!(loaded.map_pos, saved.map_pos);
+            assert_eq!(loaded.window_open, saved.window_open);
+        }
+    }
+
+    #[test]
+    fn fails_to_load_a_missing_file() {
+        let path = std::env::temp_dir().join(format!("chord_map_{}.json", uuid::Uuid::new_v4()));
+
+        assert!(matches!(
+            ChordMapState::load(&path),
+            Err(ChordMapLoadError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn lists_chords_as_text() {
+        let mut state = ChordMapState {
+            chord_views: vec![
+                ChordViewContext::new(Note::new(C, 4, NoteModifier::Natural), ChordQuality::Major),
+                ChordViewContext::new(Note::new(A, 3, NoteModifier::Flat), ChordQuality::Minor7th),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            state.chords_as_text(),
+            "C4 Major: C4 E4 G4\nAb3 Minor 7th: Ab3 B3 Eb4 Gb4"
+        );
+
+        state.remove_last_chord();
+        assert_eq!(state.chords_as_text(), "C4 Major: C4 E4 G4");
+
+        assert_eq!(ChordMapState::default().chords_as_text(), "");
+    }
+}
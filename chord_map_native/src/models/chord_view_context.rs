@@ -1,12 +1,15 @@
 use chord_map_egui::models::chord_context::ChordContext;
 use eframe::{egui::Id, epaint::Pos2};
 use note_lib::{ChordQuality, Note};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ChordViewContext {
     id: Uuid,
     pub chord_context: ChordContext,
+    /// Unsaved edits are dropped when the map is saved.
+    #[serde(skip)]
     pub editing_chord_context: Option<ChordContext>,
     pub window_open: bool,
 
@@ -1,12 +1,12 @@
 use chord_map_egui::widgets::chord_view;
 use eframe::{
-    egui::{Area, Context, Frame},
+    egui::{Area, Context, Frame, Response},
     emath::Align2,
 };
 
 use crate::models::chord_view_context::ChordViewContext;
 
-pub fn chord_map_item(ctx: &Context, chord_ctx: &mut ChordViewContext) {
+pub fn chord_map_item(ctx: &Context, chord_ctx: &mut ChordViewContext) -> Response {
     let chord_id = chord_ctx.id().to_string() + "_display";
     let area_id: eframe::egui::Id = chord_id.clone().into();
 
@@ -38,4 +38,6 @@ pub fn chord_map_item(ctx: &Context, chord_ctx: &mut ChordViewContext) {
     let dragged_delta = area_response.response.drag_delta();
 
     chord_ctx.map_pos += dragged_delta;
+
+    area_response.response
 }
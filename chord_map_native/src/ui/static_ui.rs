@@ -1,28 +1,24 @@
 
+use chord_map_egui::widgets::piano_keyboard_widget;
 use eframe::{
     egui::{Context},
 };
-use note_lib::{ChordQuality, Note, NoteModifier, C};
 
-use crate::models::{chord_map_state::ChordMapState, chord_view_context::ChordViewContext};
+use crate::models::chord_map_state::ChordMapState;
 
 use super::app_widgets::{chord_edit_window::chord_edit_window, chord_map_item::chord_map_item};
 
 pub fn main_ui(ctx: &Context, app_context: &mut ChordMapState) {
-    let ChordMapState {
-        chord_views: ref mut chord_ctxs,
-        ..
-    } = app_context;
-
     eframe::egui::CentralPanel::default().show(ctx, |ui| {
-        let clicked = ui.button("Add Chord").clicked();
-        if clicked {
-            let mut new_ctx =
-                ChordViewContext::new(Note::new(C, 4, NoteModifier::Natural), ChordQuality::Major);
-            new_ctx.window_open = true;
-            new_ctx.set_position(ctx.screen_rect().center());
-            chord_ctxs.push(new_ctx);
-        }
+        ui.horizontal(|ui| {
+            if ui.button("Add Chord").clicked() {
+                app_context.add_chord(ctx.screen_rect().center());
+            }
+            if ui.button("Copy Chords").clicked() {
+                let text = app_context.chords_as_text();
+                ctx.output_mut(|o| o.copied_text = text);
+            }
+        });
     });
 }
 
@@ -40,10 +36,38 @@ pub fn chords_edit_windows(ctx: &Context, app_context: &mut ChordMapState) {
 pub fn chords_display(ctx: &Context, app_context: &mut ChordMapState) {
     let ChordMapState {
         ref mut chord_views,
+        ref mut selected_chord,
         ..
     } = app_context;
 
     for chord_ctx in chord_views.iter_mut().filter(|ctx| !ctx.window_open) {
-        chord_map_item(ctx, chord_ctx);
+        let response = chord_map_item(ctx, chord_ctx);
+
+        if response.drag_started() || response.clicked() {
+            selected_chord.replace(chord_ctx.id());
+        }
     }
 }
+
+pub fn chord_keyboard_panel(ctx: &Context, app_context: &mut ChordMapState) {
+    let ChordMapState {
+        ref mut chord_views,
+        ref selected_chord,
+        ..
+    } = app_context;
+
+    let selected_view = chord_views
+        .iter_mut()
+        .find(|chord_view| Some(chord_view.id()) == *selected_chord);
+
+    eframe::egui::SidePanel::right("chord_keyboard").show(ctx, |ui| match selected_view {
+        Some(chord_view) => {
+            ui.heading(format!("{}", chord_view.chord_context));
+            let pitch_classes = chord_view.chord_context.get_calculated_chord().pitch_classes();
+            piano_keyboard_widget(ui, &pitch_classes);
+        }
+        None => {
+            ui.label("Select a chord to see its notes.");
+        }
+    });
+}
@@ -0,0 +1,16 @@
+/// The order in which the notes of a chord are played when it's arpeggiated.
+/// See [`crate::Chord::arpeggiate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, strum_macros::EnumIter)]
+pub enum ArpeggioDirection {
+    /// From the lowest note to the highest.
+    #[default]
+    Up,
+    /// From the highest note to the lowest.
+    Down,
+    /// Up, then back down without repeating the highest or lowest note, so the
+    /// pattern can be looped.
+    UpDown,
+    /// Down, then back up without repeating the lowest or highest note, so the
+    /// pattern can be looped.
+    DownUp,
+}
@@ -1,6 +1,41 @@
-use std::ops::Add;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    ops::Add,
+};
 
-use crate::Note;
+use strum::IntoEnumIterator;
+
+use crate::{
+    AbstractNote, ArpeggioDirection, ChordQuality, ChordRegister, Key, ModifierPreference, Note,
+    NoteModifier, NoteRangeError, Octave, RawNote, Scale, ScaleMode, Semitone, SimpleInterval,
+};
+
+/// The widest range, in semitones, that [`Chord::all_voicings_in_range`] will
+/// search. With two octaves each pitch class has at most three candidate notes,
+/// which keeps the amount of voicings manageable.
+pub const MAX_VOICING_RANGE_SEMITONES: Semitone = 24;
+
+/// The most a single voice's movement counts toward
+/// [`Chord::voicing_similarity`], an octave.
+const VOICING_SIMILARITY_MAX_MOVEMENT: Semitone = 12;
+
+/// How many frets a hand can comfortably cover, which is the span that
+/// [`Chord::guitar_voicing_in_position`] searches.
+const GUITAR_POSITION_SPAN: u8 = 4;
+
+/// Gets the notes of a guitar's strings in standard tuning, from the lowest
+/// string to the highest: E2 A2 D3 G3 B3 E4.
+pub fn standard_guitar_tuning() -> [Note; 6] {
+    [
+        Note::new(RawNote::E, 2, NoteModifier::Natural),
+        Note::new(RawNote::A, 2, NoteModifier::Natural),
+        Note::new(RawNote::D, 3, NoteModifier::Natural),
+        Note::new(RawNote::G, 3, NoteModifier::Natural),
+        Note::new(RawNote::B, 3, NoteModifier::Natural),
+        Note::new(RawNote::E, 4, NoteModifier::Natural),
+    ]
+}
 
 #[derive(PartialEq, Clone, Debug, Default)]
 pub struct Chord {
@@ -12,10 +47,178 @@ impl Chord {
         Chord { notes }
     }
 
+    /// Creates a chord from MIDI note numbers, where 60 is C4. Black keys are
+    /// spelled using `modifier_preference`, and numbers above 127 are ignored.
+    ///
+    /// ```rust
+    /// use note_lib::{Chord, ModifierPreference, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Chord::from_midi_notes(&[60, 63, 67], ModifierPreference::Flat);
+    /// assert_eq!(
+    ///     chord.notes(),
+    ///     &[
+    ///         Note::new(C, 4, NoteModifier::Natural),
+    ///         Note::new(E, 4, NoteModifier::Flat),
+    ///         Note::new(G, 4, NoteModifier::Natural),
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_midi_notes(midi_notes: &[u8], modifier_preference: ModifierPreference) -> Chord {
+        Chord::new(
+            midi_notes
+                .iter()
+                .filter_map(|midi_number| Note::from_midi_number(*midi_number, modifier_preference))
+                .collect(),
+        )
+    }
+
     pub fn notes(&self) -> &[Note] {
         &self.notes
     }
 
+    /// Gets the number of notes in this chord, counting doubled notes.
+    pub fn num_voices(&self) -> usize {
+        self.notes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Whether this chord has exactly two notes.
+    pub fn is_dyad(&self) -> bool {
+        self.num_voices() == 2
+    }
+
+    /// Whether this chord has exactly three notes. This counts voices, so a
+    /// C major triad with a doubled root is not a triad.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    ///
+    /// assert!(ChordQuality::Major.to_chord(c4).is_triad());
+    /// assert!(!ChordQuality::Major7th.to_chord(c4).is_triad());
+    /// ```
+    pub fn is_triad(&self) -> bool {
+        self.num_voices() == 3
+    }
+
+    /// Whether this chord has exactly four notes, like a seventh chord.
+    pub fn is_tetrad(&self) -> bool {
+        self.num_voices() == 4
+    }
+
+    /// Whether this chord has five or more notes, like a ninth chord.
+    pub fn is_extended(&self) -> bool {
+        self.num_voices() >= 5
+    }
+
+    /// Gets the note at index `n`, in the order the notes were given rather
+    /// than by pitch.
+    pub fn note_at_position(&self, n: usize) -> Option<Note> {
+        self.notes.get(n).copied()
+    }
+
+    /// Gets the lowest sounding note of this chord, or `None` if it is empty.
+    pub fn bass_note(&self) -> Option<Note> {
+        self.notes
+            .iter()
+            .min_by_key(|note| note.to_semitones_from_c0())
+            .copied()
+    }
+
+    /// Gets the highest sounding note of this chord, or `None` if it is empty.
+    pub fn soprano_note(&self) -> Option<Note> {
+        self.notes
+            .iter()
+            .max_by_key(|note| note.to_semitones_from_c0())
+            .copied()
+    }
+
+    /// Whether every note of this chord fits within an octave of the bass, as
+    /// in a close voicing like C4 E4 G4. An empty chord is neither close nor
+    /// open.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let close = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    /// let open = Note::new(C, 3, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// assert!(close.is_close_voicing());
+    /// assert!(open.is_open_voicing());
+    /// ```
+    pub fn is_close_voicing(&self) -> bool {
+        self.outer_voice_span().is_some_and(|span| span <= 12)
+    }
+
+    /// Whether this chord spreads wider than an octave from its bass to its
+    /// top note. See [`Chord::is_close_voicing`].
+    pub fn is_open_voicing(&self) -> bool {
+        self.outer_voice_span().is_some_and(|span| span > 12)
+    }
+
+    /// The semitones from the bass up to the soprano.
+    fn outer_voice_span(&self) -> Option<Semitone> {
+        Some(self.soprano_note()?.to_semitones_from_c0() - self.bass_note()?.to_semitones_from_c0())
+    }
+
+    /// Gets how many semitones the top voice moves from this chord to
+    /// `target`. The count is positive when the soprano moves up and negative
+    /// when it moves down. Returns `None` if either chord is empty.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, A, C, D, E, F, G};
+    ///
+    /// let c_major = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    /// let d_minor = Note::new(D, 4, NoteModifier::Natural)
+    ///     + Note::new(F, 4, NoteModifier::Natural)
+    ///     + Note::new(A, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!(c_major.soprano_motion_to(&d_minor), Some(2));
+    /// assert_eq!(d_minor.soprano_motion_to(&c_major), Some(-2));
+    /// ```
+    pub fn soprano_motion_to(&self, target: &Chord) -> Option<Semitone> {
+        Some(motion_between(self.soprano_note()?, target.soprano_note()?))
+    }
+
+    /// Gets how far the bass moves from this chord to `target`, like
+    /// [`Chord::soprano_motion_to`] does for the top voice.
+    pub fn bass_motion_to(&self, target: &Chord) -> Option<Semitone> {
+        Some(motion_between(self.bass_note()?, target.bass_note()?))
+    }
+
+    /// Gets a copy of this chord with the notes at indexes `i` and `j`
+    /// swapped, or `None` if either index is out of bounds.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!((c4 + e4 + g4).swap_notes(0, 2), Some(g4 + e4 + c4));
+    /// assert_eq!((c4 + e4 + g4).swap_notes(0, 3), None);
+    /// ```
+    pub fn swap_notes(&self, i: usize, j: usize) -> Option<Chord> {
+        if i >= self.notes.len() || j >= self.notes.len() {
+            return None;
+        }
+
+        let mut notes = self.notes.clone();
+        notes.swap(i, j);
+        Some(Chord::new(notes))
+    }
+
     pub fn add_note(&mut self, note: Note) {
         self.notes.push(note);
     }
@@ -24,13 +227,1134 @@ impl Chord {
         self.notes = notes;
     }
 
-    pub fn apply_inversion(&self, inversion: i8) -> Chord {
+    /// Returns a copy of this chord with its notes ordered from lowest to
+    /// highest pitch.
+    pub fn sort_by_pitch(&self) -> Chord {
+        let mut chord = self.clone();
+        chord.sort_by_pitch_mut();
+        chord
+    }
+
+    /// Orders the notes of this chord from lowest to highest pitch, in place.
+    pub fn sort_by_pitch_mut(&mut self) {
+        self.notes.sort_by_key(|note| note.to_semitones_from_c0());
+    }
+
+    /// Moves every note of this chord into the fourth octave, dropping notes
+    /// that repeat a pitch class, and sorts the result from lowest to highest.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 2, NoteModifier::Natural)
+    ///     + Note::new(G, 5, NoteModifier::Natural)
+    ///     + Note::new(E, 3, NoteModifier::Natural)
+    ///     + Note::new(C, 6, NoteModifier::Natural);
+    ///
+    /// assert_eq!(chord.compress_to_single_octave().to_string(), "[C4, E4, G4]");
+    /// ```
+    pub fn compress_to_single_octave(&self) -> Chord {
+        let mut pitch_classes = BTreeSet::new();
+        let notes = self
+            .notes
+            .iter()
+            .filter(|note| pitch_classes.insert(AbstractNote::from(**note).chromatic_index()))
+            .map(|note| AbstractNote::from(*note).to_note(4))
+            .collect();
+
+        Chord::new(notes).sort_by_pitch()
+    }
+
+    /// Stacks the notes of this chord as closely as possible above its bass
+    /// note. Going up from the bass, each note is moved to the nearest octave
+    /// above the note before it, so every step is less than an octave. A note
+    /// repeating the pitch class of the note before it ends up an octave above
+    /// it.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 6, NoteModifier::Natural)
+    ///     + Note::new(G, 7, NoteModifier::Natural);
+    ///
+    /// assert_eq!(chord.close_position().to_string(), "[C4, E4, G4]");
+    /// ```
+    pub fn close_position(&self) -> Chord {
+        let mut notes = self.sort_by_pitch().notes.into_iter();
+        let Some(bass) = notes.next() else {
+            return Chord::default();
+        };
+
+        let mut previous_semitones = bass.to_semitones_from_c0();
+        let stacked = std::iter::once(bass)
+            .chain(notes.map(|note| {
+                let abstract_note = AbstractNote::from(note);
+                let semitones_up = match (abstract_note.to_note(0).to_semitones_from_c0()
+                    - previous_semitones)
+                    .rem_euclid(12)
+                {
+                    0 => 12,
+                    semitones => semitones,
+                };
+
+                previous_semitones += semitones_up;
+                Note::place_at_semitones(abstract_note, previous_semitones)
+            }))
+            .collect();
+
+        Chord::new(stacked)
+    }
+
+    /// Gets a copy of this chord with `bass` as its first note, like the E in
+    /// a C/E slash chord. If the chord already has a note with the same pitch
+    /// class as `bass`, that note is moved to the front at `bass`'s octave,
+    /// keeping its spelling. Otherwise `bass` is added to the front.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let e3 = Note::new(E, 3, NoteModifier::Natural);
+    ///
+    /// assert_eq!((c4 + e4 + g4).with_bass(e3), e3 + c4 + g4);
+    /// ```
+    pub fn with_bass(&self, bass: Note) -> Chord {
+        let bass_pitch_class = AbstractNote::from(bass).chromatic_index();
+        let mut notes = self.notes.clone();
+
+        let bass = match notes
+            .iter()
+            .position(|note| AbstractNote::from(*note).chromatic_index() == bass_pitch_class)
+        {
+            Some(index) => AbstractNote::from(notes.remove(index)).to_note(bass.octave()),
+            None => bass,
+        };
+
+        notes.insert(0, bass);
+        Chord::new(notes)
+    }
+
+    /// Gets the set of pitch classes (0 through 11, where C is 0) that
+    /// this chord contains, regardless of octave or spelling.
+    pub fn pitch_classes(&self) -> BTreeSet<u8> {
+        self.notes
+            .iter()
+            .map(|note| AbstractNote::from(*note).chromatic_index())
+            .collect()
+    }
+
+    /// Counts how many notes of this chord fall in each [`ChordRegister`].
+    /// Every register is in the map, so registers without any notes have a
+    /// count of 0.
+    pub fn count_voices_by_register(&self) -> HashMap<ChordRegister, usize> {
+        let mut counts: HashMap<ChordRegister, usize> = ChordRegister::iter()
+            .map(|register| (register, 0))
+            .collect();
+
+        for note in &self.notes {
+            *counts.entry(ChordRegister::of_note(*note)).or_default() += 1;
+        }
+
+        counts
+    }
+
+    /// Gets the register of the average pitch of this chord's notes, or `None`
+    /// if the chord is empty.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordRegister, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 2, NoteModifier::Natural)
+    ///     + Note::new(E, 2, NoteModifier::Natural)
+    ///     + Note::new(G, 2, NoteModifier::Natural);
+    ///
+    /// assert_eq!(chord.register(), Some(ChordRegister::Bass));
+    /// ```
+    pub fn register(&self) -> Option<ChordRegister> {
+        if self.notes.is_empty() {
+            return None;
+        }
+
+        let total: Semitone = self
+            .notes
+            .iter()
+            .map(|note| note.to_semitones_from_c0())
+            .sum();
+        let average = (total as f64 / self.notes.len() as f64).round() as Semitone;
+
+        Some(ChordRegister::from_semitones_from_c0(average))
+    }
+
+    /// Gets a chord with every note of this chord and `other`, keeping the
+    /// order they first appear in. Notes are only considered the same if they
+    /// are spelled the same and in the same octave, so C#4 and Db4 are both
+    /// kept.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, B, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let b4 = Note::new(B, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!((c4 + e4).union(&(g4 + b4)), c4 + e4 + g4 + b4);
+    /// ```
+    pub fn union(&self, other: &Chord) -> Chord {
+        let mut notes: Vec<Note> = vec![];
+        for note in self.notes.iter().chain(other.notes.iter()) {
+            if !notes.contains(note) {
+                notes.push(*note);
+            }
+        }
+
+        Chord::new(notes)
+    }
+
+    /// Gets a chord with the notes of `quality` built on this chord's bass
+    /// added to it, skipping any pitch class this chord already has. This is
+    /// how a triad grows into a seventh chord, for example. An empty chord has
+    /// no bass to build on and is returned unchanged.
+    ///
+    /// Returns an error if any note of `quality` would be outside the range of
+    /// [`Octave`], rather than leaving those notes out.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, B, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let b4 = Note::new(B, 4, NoteModifier::Natural);
+    ///
+    /// let chord = (c4 + e4 + g4).extend_with_quality(ChordQuality::Major7th);
+    /// assert_eq!(chord, Ok(c4 + e4 + g4 + b4));
+    /// ```
+    pub fn extend_with_quality(&self, quality: ChordQuality) -> Result<Chord, NoteRangeError> {
+        let Some(bass) = self.sort_by_pitch().notes.first().copied() else {
+            return Ok(self.clone());
+        };
+
+        let pitch_classes = self.pitch_classes();
+        let extensions: Vec<Note> = quality
+            .try_to_notes(bass)?
+            .into_iter()
+            .filter(|note| !pitch_classes.contains(&AbstractNote::from(*note).chromatic_index()))
+            .collect();
+
+        Ok(self.union(&Chord::new(extensions)))
+    }
+
+    /// Gets a chord with only the notes of this chord that are also in
+    /// `other`, in this chord's order. Like [`Chord::union`], notes must be
+    /// spelled the same and in the same octave to match.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, B, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let b4 = Note::new(B, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!((c4 + e4 + g4).intersection(&(e4 + g4 + b4)), e4 + g4);
+    /// ```
+    pub fn intersection(&self, other: &Chord) -> Chord {
+        let mut notes: Vec<Note> = vec![];
+        for note in self.notes.iter().filter(|note| other.notes.contains(note)) {
+            if !notes.contains(note) {
+                notes.push(*note);
+            }
+        }
+
+        Chord::new(notes)
+    }
+
+    /// Gets the pitch classes that this chord and `other` share, regardless of
+    /// octave or spelling.
+    pub fn pitch_class_intersection(&self, other: &Chord) -> BTreeSet<u8> {
+        self.pitch_classes()
+            .intersection(&other.pitch_classes())
+            .copied()
+            .collect()
+    }
+
+    /// Tries to find the root and [`ChordQuality`] of this chord by comparing
+    /// its pitch classes against the pattern of every known quality. Each note
+    /// is tried as the root, starting from the bass, so a root position reading
+    /// is preferred when a set of notes can be named multiple ways (C6 vs Am7).
+    ///
+    /// The returned root is the lowest note in the chord with the root's pitch class.
+    ///
+    /// ```rust
+    /// use note_lib::{Chord, ChordQuality, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Chord::new(vec![
+    ///     Note::new(E, 4, NoteModifier::Natural),
+    ///     Note::new(G, 4, NoteModifier::Natural),
+    ///     Note::new(C, 5, NoteModifier::Natural),
+    /// ]);
+    ///
+    /// let (root, quality) = chord.identify_quality().unwrap();
+    /// assert_eq!(root, Note::new(C, 5, NoteModifier::Natural));
+    /// assert_eq!(quality, ChordQuality::Major);
+    /// ```
+    pub fn identify_quality(&self) -> Option<(Note, ChordQuality)> {
+        let pitch_classes = self.pitch_classes();
+
+        let mut tried_roots = BTreeSet::new();
+        for root in self.sort_by_pitch().notes {
+            let root_pitch_class = AbstractNote::from(root).chromatic_index();
+            if !tried_roots.insert(root_pitch_class) {
+                continue;
+            }
+
+            let pattern_from_root: BTreeSet<Semitone> = pitch_classes
+                .iter()
+                .map(|pitch_class| {
+                    (*pitch_class as Semitone - root_pitch_class as Semitone).rem_euclid(12)
+                })
+                .collect();
+
+            let quality = ChordQuality::iter().find(|quality| {
+                let quality_pattern: BTreeSet<Semitone> = quality
+                    .to_semitone_pattern()
+                    .iter()
+                    .map(|semitones| semitones.rem_euclid(12))
+                    .collect();
+
+                quality_pattern == pattern_from_root
+            });
+
+            if let Some(quality) = quality {
+                return Some((root, quality));
+            }
+        }
+
+        None
+    }
+
+    /// Gets the chord symbol of this chord in slash notation, like "C/E" for a
+    /// C major chord with E in the bass. Chords with their root in the bass
+    /// have no slash, like "Am7". Major triads are written as just their root.
+    ///
+    /// Returns `None` if the quality of the chord can't be identified.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let c5 = Note::new(C, 5, NoteModifier::Natural);
+    ///
+    /// assert_eq!((e4 + g4 + c5).slash_notation(), Some("C/E".to_string()));
+    /// ```
+    pub fn slash_notation(&self) -> Option<String> {
+        let (root, quality) = self.identify_quality()?;
+        let bass = self.sort_by_pitch().notes.first().copied()?;
+
+        let root = AbstractNote::from(root);
+        let symbol = match quality {
+            ChordQuality::Major => root.to_string(),
+            _ => format!("{}{}", root, quality),
+        };
+
+        let bass = AbstractNote::from(bass);
+        if bass.chromatic_index() == root.chromatic_index() {
+            Some(symbol)
+        } else {
+            Some(format!("{}/{}", symbol, bass))
+        }
+    }
+
+    /// Gets the full name of this chord, its root followed by the long name of
+    /// its quality, like "C Major 7th". Inversions are named after their
+    /// root, so the bass isn't part of the name.
+    ///
+    /// Returns `None` if the quality of the chord can't be identified.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, B, C, E, G};
+    ///
+    /// let chord = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural)
+    ///     + Note::new(B, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!(chord.name(), Some("C Major 7th".to_string()));
+    /// ```
+    pub fn name(&self) -> Option<String> {
+        let (root, quality) = self.identify_quality()?;
+
+        Some(format!("{} {:#}", AbstractNote::from(root), quality))
+    }
+
+    /// Gets the chord symbol of this chord, like "Cmaj7", or "C/E" when the
+    /// chord is inverted. This is the same as [`Chord::slash_notation`].
+    ///
+    /// Returns `None` if the quality of the chord can't be identified.
+    pub fn short_name(&self) -> Option<String> {
+        self.slash_notation()
+    }
+
+    /// Gets a copy of this chord without the note `interval` above its root,
+    /// like a major seventh chord with no fifth. Every octave of that note is
+    /// removed. Returns `None` if the quality of the chord can't be
+    /// identified, or if it has no note at that interval.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, SimpleInterval, B, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let b4 = Note::new(B, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!(
+    ///     (c4 + e4 + g4 + b4).omit_note_by_interval(SimpleInterval::PerfectFifth),
+    ///     Some(c4 + e4 + b4)
+    /// );
+    /// ```
+    pub fn omit_note_by_interval(&self, interval: SimpleInterval) -> Option<Chord> {
+        let (root, _) = self.identify_quality()?;
+        let root_semitones = root.to_semitones_from_c0();
+        let is_omitted = |note: &Note| {
+            (note.to_semitones_from_c0() - root_semitones).rem_euclid(12)
+                == interval.semitones().rem_euclid(12)
+        };
+
+        let notes: Vec<Note> = self
+            .notes
+            .iter()
+            .copied()
+            .filter(|note| !is_omitted(note))
+            .collect();
+        if notes.len() == self.notes.len() {
+            return None;
+        }
+
+        Some(Chord::new(notes))
+    }
+
+    /// Builds a shell voicing of this chord, keeping only the root, third and
+    /// seventh. The fifth and any extensions are dropped. The lowest note of each
+    /// kept pitch class is used.
+    ///
+    /// Returns `None` if the quality of the chord can't be identified, or if
+    /// the chord doesn't have both a third and a seventh.
+    pub fn shell_voicing(&self) -> Option<Chord> {
+        let (root, quality) = self.identify_quality()?;
+        let root_semitones = root.to_semitones_from_c0();
+
+        // A diminished 7th chord's seventh is enharmonically a major sixth.
+        let seventh_semitones: &[Semitone] = match quality {
+            ChordQuality::Diminished7th => &[9],
+            _ => &[10, 11],
+        };
+
+        let sorted = self.sort_by_pitch();
+        let find_note = |semitones_above_root: &[Semitone]| {
+            sorted.notes.iter().copied().find(|note| {
+                let semitones = (note.to_semitones_from_c0() - root_semitones).rem_euclid(12);
+                semitones_above_root.contains(&semitones)
+            })
+        };
+
+        let third = find_note(&[3, 4])?;
+        let seventh = find_note(seventh_semitones)?;
+
+        Some(Chord::new(vec![root, third, seventh]).sort_by_pitch())
+    }
+
+    /// Reduces this chord to a three note triad of its root, third and fifth,
+    /// dropping sevenths and extensions. The lowest note of each kept pitch
+    /// class is used, so a Cmaj9 reduces to a C major triad. For suspended
+    /// chords the suspended note takes the place of the third.
+    ///
+    /// Returns `None` if the quality of the chord can't be identified.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let cmaj9 = ChordQuality::Major9th.to_chord(c4);
+    ///
+    /// assert_eq!(cmaj9.reduce_to_triadic_form(), Some(ChordQuality::Major.to_chord(c4)));
+    /// ```
+    pub fn reduce_to_triadic_form(&self) -> Option<Chord> {
+        let (root, quality) = self.identify_quality()?;
+        let root_semitones = root.to_semitones_from_c0();
+
+        let sorted = self.sort_by_pitch();
+        let notes = quality
+            .to_semitone_pattern()
+            .iter()
+            .take(3)
+            .map(|semitones_above_root| {
+                sorted.notes.iter().copied().find(|note| {
+                    (note.to_semitones_from_c0() - root_semitones).rem_euclid(12)
+                        == semitones_above_root.rem_euclid(12)
+                })
+            })
+            .collect::<Option<Vec<Note>>>()?;
+
+        Some(Chord::new(notes).sort_by_pitch())
+    }
+
+    /// Checks whether this chord has every pitch class of `quality` built on
+    /// its bass note. Extra notes are allowed, so a C major 7th is complete for
+    /// [`ChordQuality::Major`], but C4 E4 is not since it's missing the fifth.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C, E};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    ///
+    /// assert!(!(c4 + e4).is_complete_for_quality(ChordQuality::Major));
+    /// ```
+    pub fn is_complete_for_quality(&self, quality: ChordQuality) -> bool {
+        let Some(bass) = self.sort_by_pitch().notes.first().copied() else {
+            return false;
+        };
+
+        let bass_pitch_class = AbstractNote::from(bass).chromatic_index() as Semitone;
+        let pitch_classes = self.pitch_classes();
+
+        quality.to_semitone_pattern().iter().all(|semitones| {
+            pitch_classes.contains(&((bass_pitch_class + semitones).rem_euclid(12) as u8))
+        })
+    }
+
+    /// Builds a drop-2 voicing of a four note chord by taking the chord in
+    /// order of pitch and dropping the second-highest note down an octave.
+    /// For example, C4 E4 G4 B4 becomes G3 C4 E4 B4.
+    ///
+    /// Returns `None` if the chord doesn't have exactly four notes, or if the
+    /// dropped note would fall below [`Octave::MIN`].
+    pub fn drop2_voicing(&self) -> Option<Chord> {
+        if !self.is_tetrad() {
+            return None;
+        }
+
+        let mut notes = self.sort_by_pitch().notes;
+        let dropped = notes.remove(2);
+        notes.insert(
+            0,
+            Note::new(
+                dropped.raw_note(),
+                dropped.octave().checked_sub(1).ok()?,
+                dropped.modifier(),
+            ),
+        );
+
+        Some(Chord::new(notes).sort_by_pitch())
+    }
+
+    /// Enumerates every voicing of this chord where each pitch class appears
+    /// exactly once within `lo` and `hi` (inclusive). Each voicing is sorted
+    /// from lowest to highest pitch, and the voicings themselves are ordered by
+    /// their notes from the bass up. Pitch classes keep the spelling of the
+    /// first note in this chord that uses them.
+    ///
+    /// The amount of voicings grows combinatorially, so an empty list is returned
+    /// if the range is wider than [`MAX_VOICING_RANGE_SEMITONES`].
+    pub fn all_voicings_in_range(&self, lo: Note, hi: Note) -> Vec<Chord> {
+        let lo_semitones = lo.to_semitones_from_c0();
+        let hi_semitones = hi.to_semitones_from_c0();
+
+        if self.notes.is_empty()
+            || hi_semitones < lo_semitones
+            || hi_semitones - lo_semitones > MAX_VOICING_RANGE_SEMITONES
+        {
+            return vec![];
+        }
+
+        let mut seen_pitch_classes = BTreeSet::new();
+        let candidates_per_pitch_class: Vec<Vec<Note>> = self
+            .notes
+            .iter()
+            .filter(|note| seen_pitch_classes.insert(AbstractNote::from(**note).chromatic_index()))
+            .map(|note| {
+                ((lo.octave().value() - 1)..=(hi.octave().value() + 1))
+                    .filter_map(|octave| Octave::new(octave).ok())
+                    .map(|octave| Note::new(note.raw_note(), octave, note.modifier()))
+                    .filter(|candidate| {
+                        (lo_semitones..=hi_semitones).contains(&candidate.to_semitones_from_c0())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut voicings: Vec<Vec<Note>> = vec![vec![]];
+        for candidates in candidates_per_pitch_class {
+            voicings = voicings
+                .into_iter()
+                .flat_map(|voicing| {
+                    candidates.iter().map(move |candidate| {
+                        let mut voicing = voicing.clone();
+                        voicing.push(*candidate);
+                        voicing
+                    })
+                })
+                .collect();
+        }
+
+        let mut voicings: Vec<Chord> = voicings
+            .into_iter()
+            .map(|notes| Chord::new(notes).sort_by_pitch())
+            .collect();
+        voicings.sort_by_key(|chord| {
+            chord
+                .notes
+                .iter()
+                .map(|note| note.to_semitones_from_c0())
+                .collect::<Vec<Semitone>>()
+        });
+        voicings.dedup();
+
+        voicings
+    }
+
+    /// Finds a way to play this chord on a guitar with strings tuned to
+    /// `tuning`, from the lowest string to the highest. The result has the
+    /// fret to play on each string, or `None` for muted strings. Frets are
+    /// counted from the nut, and a string played at the capo's fret sounds
+    /// open.
+    ///
+    /// Apart from open strings, only the 4 frets starting at `position` frets
+    /// above the capo are used. Every pitch class of the chord must sound, the
+    /// lowest sounding note must be the chord's bass note, at most four
+    /// strings can be fretted, and muted strings can only be on the outside
+    /// of the strings that are played. Of the voicings that fit, the one with
+    /// the fewest muted strings, then the fewest fretted strings, then the
+    /// lowest frets is chosen. Returns `None` if no voicing fits, or if the
+    /// position would reach past fret 255.
+    ///
+    /// ```rust
+    /// use note_lib::{standard_guitar_tuning, ChordQuality, Note, NoteModifier, C};
+    ///
+    /// let c_major = ChordQuality::Major.to_chord(Note::new(C, 3, NoteModifier::Natural));
+    /// let voicing = c_major.guitar_voicing_in_position(standard_guitar_tuning(), 0, 0);
+    ///
+    /// assert_eq!(voicing, Some(vec![None, Some(3), Some(2), Some(0), Some(1), Some(0)]));
+    /// ```
+    pub fn guitar_voicing_in_position(
+        &self,
+        tuning: [Note; 6],
+        capo: u8,
+        position: u8,
+    ) -> Option<Vec<Option<u8>>> {
+        let bass_pitch_class =
+            AbstractNote::from(*self.sort_by_pitch().notes.first()?).chromatic_index();
+        let pitch_classes = self.pitch_classes();
+        let lowest_fret = capo.checked_add(position)?;
+        let past_highest_fret = lowest_fret.checked_add(GUITAR_POSITION_SPAN)?;
+        let first_fretted = capo.checked_add(1)?;
+
+        let sounding =
+            |string: usize, fret: u8| tuning[string].to_semitones_from_c0() + fret as Semitone;
+        let pitch_class = |semitones: Semitone| semitones.rem_euclid(12) as u8;
+
+        // Each string can be muted, or played at any fret that sounds a
+        // pitch class of the chord.
+        let string_options: Vec<Vec<Option<u8>>> = (0..tuning.len())
+            .map(|string| {
+                let frets =
+                    std::iter::once(capo).chain(lowest_fret.max(first_fretted)..past_highest_fret);
+                std::iter::once(None)
+                    .chain(
+                        frets
+                            .filter(|fret| {
+                                pitch_classes.contains(&pitch_class(sounding(string, *fret)))
+                            })
+                            .map(Some),
+                    )
+                    .collect()
+            })
+            .collect();
+
+        let voicings =
+            string_options
+                .iter()
+                .fold(vec![vec![]], |voicings: Vec<Vec<Option<u8>>>, options| {
+                    voicings
+                        .into_iter()
+                        .flat_map(|voicing| {
+                            options.iter().map(move |option| {
+                                let mut voicing = voicing.clone();
+                                voicing.push(*option);
+                                voicing
+                            })
+                        })
+                        .collect()
+                });
+
+        voicings
+            .into_iter()
+            .filter(|voicing| {
+                let played: Vec<usize> = (0..voicing.len())
+                    .filter(|string| voicing[*string].is_some())
+                    .collect();
+                let (Some(first), Some(last)) = (played.first(), played.last()) else {
+                    return false;
+                };
+                if last - first + 1 != played.len() {
+                    return false;
+                }
+
+                let notes: Vec<Semitone> = played
+                    .iter()
+                    .map(|string| sounding(*string, voicing[*string].unwrap()))
+                    .collect();
+                let covers_chord = pitch_classes.iter().all(|chord_pitch_class| {
+                    notes
+                        .iter()
+                        .any(|note| pitch_class(*note) == *chord_pitch_class)
+                });
+                let fretted = voicing
+                    .iter()
+                    .flatten()
+                    .filter(|fret| **fret != capo)
+                    .count();
+
+                covers_chord
+                    && fretted <= 4
+                    && notes.iter().min().map(|bass| pitch_class(*bass)) == Some(bass_pitch_class)
+            })
+            .min_by_key(|voicing| {
+                let muted = voicing.iter().filter(|fret| fret.is_none()).count();
+                let fretted = voicing
+                    .iter()
+                    .flatten()
+                    .filter(|fret| **fret != capo)
+                    .count();
+                let fret_total: u32 = voicing.iter().flatten().map(|fret| *fret as u32).sum();
+                (muted, fretted, fret_total)
+            })
+    }
+
+    /// Respells each note in this chord using the accidentals of the key
+    /// given by `root` and `mode`, using [`Scale::spell_note`]. Notes keep their
+    /// pitch, so respelling C4 as B# gives B#3. Notes whose pitch class isn't
+    /// in the key are spelled with the key's accidentals, using
+    /// [`ModifierPreference::for_key`], so C# becomes Db in F major.
+    pub fn respell_for_key(&self, root: AbstractNote, mode: ScaleMode) -> Chord {
+        let scale = Scale::new(root, mode);
+        let key = Key::new(root, mode);
+
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| {
+                if scale.degree_of_note(AbstractNote::from(*note)).is_none() {
+                    return Note::from_semitones_in_key(note.to_semitones_from_c0(), &key);
+                }
+
+                let spelled = scale.spell_note(AbstractNote::from(*note));
+                let octave_difference = (note.to_semitones_from_c0()
+                    - spelled.to_note(note.octave()).to_semitones_from_c0())
+                    / 12;
+                spelled.to_note(note.octave() + octave_difference)
+            })
+            .collect();
+
+        Chord::new(notes)
+    }
+
+    /// Voices `target` so that each note of this chord moves as little as
+    /// possible to reach a pitch class of `target`.
+    ///
+    /// Voices are paired with target pitch classes greedily, always taking the
+    /// pair with the smallest semitone movement first. The note at each position
+    /// of the result is where the voice at the same position in this chord moved,
+    /// so voices keep their order even if they end up crossing. If this chord has
+    /// more voices than `target` has pitch classes, the extra voices double their
+    /// nearest target pitch class. If it has fewer, the leftover pitch classes are
+    /// appended, each placed as close as possible to one of this chord's voices.
+    ///
+    /// Pitch classes keep the spelling used in `target`.
+    pub fn voice_lead_to(&self, target: &Chord) -> Chord {
+        if self.notes.is_empty() {
+            return target.clone();
+        }
+
+        let mut seen_pitch_classes = BTreeSet::new();
+        let target_notes: Vec<Note> = target
+            .notes
+            .iter()
+            .copied()
+            .filter(|note| seen_pitch_classes.insert(AbstractNote::from(*note).chromatic_index()))
+            .collect();
+
+        if target_notes.is_empty() {
+            return Chord::default();
+        }
+
+        let nearest = |voice: &Note, target_note: &Note| {
+            let voice_semitones = voice.to_semitones_from_c0();
+            let nearest_note = ((voice.octave().value() - 1)..=(voice.octave().value() + 1))
+                .filter_map(|octave| Octave::new(octave).ok())
+                .map(|octave| Note::new(target_note.raw_note(), octave, target_note.modifier()))
+                .min_by_key(|candidate| (candidate.to_semitones_from_c0() - voice_semitones).abs())
+                .unwrap();
+            let movement = (nearest_note.to_semitones_from_c0() - voice_semitones).abs();
+            (movement, nearest_note)
+        };
+
+        let mut moved_voices: Vec<Option<Note>> = vec![None; self.notes.len()];
+        let mut used_targets = vec![false; target_notes.len()];
+
+        // Pair each voice with a distinct pitch class while both remain.
+        while moved_voices.iter().any(|v| v.is_none()) && used_targets.iter().any(|used| !used) {
+            let (voice_index, target_index, note) = self
+                .notes
+                .iter()
+                .enumerate()
+                .filter(|(voice_index, _)| moved_voices[*voice_index].is_none())
+                .flat_map(|(voice_index, voice)| {
+                    target_notes
+                        .iter()
+                        .enumerate()
+                        .filter(|(target_index, _)| !used_targets[*target_index])
+                        .map(move |(target_index, target_note)| {
+                            let (movement, note) = nearest(voice, target_note);
+                            (movement, voice_index, target_index, note)
+                        })
+                })
+                .min_by_key(|(movement, ..)| *movement)
+                .map(|(_, voice_index, target_index, note)| (voice_index, target_index, note))
+                .unwrap();
+
+            moved_voices[voice_index] = Some(note);
+            used_targets[target_index] = true;
+        }
+
+        // Any voices left over double whichever pitch class is closest.
+        let mut notes: Vec<Note> = self
+            .notes
+            .iter()
+            .zip(moved_voices)
+            .map(|(voice, moved)| {
+                moved.unwrap_or_else(|| {
+                    target_notes
+                        .iter()
+                        .map(|target_note| nearest(voice, target_note))
+                        .min_by_key(|(movement, _)| *movement)
+                        .unwrap()
+                        .1
+                })
+            })
+            .collect();
+
+        // Any pitch classes left over are placed close to the existing voices.
+        for (target_note, _) in target_notes
+            .iter()
+            .zip(used_targets)
+            .filter(|(_, used)| !used)
+        {
+            let (_, note) = self
+                .notes
+                .iter()
+                .map(|voice| nearest(voice, target_note))
+                .min_by_key(|(movement, _)| *movement)
+                .unwrap();
+            notes.push(note);
+        }
+
+        Chord::new(notes)
+    }
+
+    /// Scores how close this voicing is to `other`, from 0.0 to 1.0. Voices
+    /// are paired up in order of pitch, which moves them the least in total,
+    /// and each voice's movement counts up to an octave. A score of 1.0 means
+    /// the voicings are the same, and 0.0 means every voice moves an octave or
+    /// more.
+    ///
+    /// Returns [`VoicingSimilarityError::VoiceMismatch`] if the chords have a
+    /// different number of voices.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C, F};
+    ///
+    /// let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+    /// let f_major = ChordQuality::Major.to_chord(Note::new(F, 4, NoteModifier::Natural));
+    ///
+    /// assert_eq!(c_major.voicing_similarity(&c_major), Ok(1.0));
+    /// assert!(c_major.voicing_similarity(&f_major).unwrap() < 1.0);
+    /// ```
+    pub fn voicing_similarity(&self, other: &Chord) -> Result<f32, VoicingSimilarityError> {
+        if self.num_voices() != other.num_voices() {
+            return Err(VoicingSimilarityError::VoiceMismatch);
+        }
+        if self.is_empty() {
+            return Ok(1.0);
+        }
+
+        let movement: Semitone = self
+            .sort_by_pitch()
+            .notes
+            .iter()
+            .zip(other.sort_by_pitch().notes.iter())
+            .map(|(from, to)| {
+                (to.to_semitones_from_c0() - from.to_semitones_from_c0())
+                    .abs()
+                    .min(VOICING_SIMILARITY_MAX_MOVEMENT)
+            })
+            .sum();
+        let max_movement = VOICING_SIMILARITY_MAX_MOVEMENT * self.num_voices() as Semitone;
+
+        Ok(1.0 - movement as f32 / max_movement as f32)
+    }
+
+    /// Collects the notes of the scale given by `root` and `mode` whose pitch
+    /// classes aren't in this chord. These are the tensions (9ths, 11ths and
+    /// 13ths) that could extend the chord in the context of that scale.
+    ///
+    /// The notes are spelled as the scale spells them, and ordered upward from
+    /// the chord's root (or its bass note, if the chord's quality can't be
+    /// identified). For example, G7 in C Ionian gives A, C and E.
+    pub fn available_tensions(&self, root: AbstractNote, mode: ScaleMode) -> Vec<AbstractNote> {
+        let pitch_classes = self.pitch_classes();
+
+        let chord_root = self
+            .identify_quality()
+            .map(|(root, _)| root)
+            .or_else(|| self.sort_by_pitch().notes.first().copied());
+        let chord_root_pitch_class = chord_root
+            .map(|note| AbstractNote::from(note).chromatic_index())
+            .unwrap_or(0);
+
+        let mut tensions: Vec<AbstractNote> = Scale::new(root, mode)
+            .all_notes()
+            .into_iter()
+            .filter(|note| !pitch_classes.contains(&note.chromatic_index()))
+            .collect();
+        tensions.sort_by_key(|note| {
+            (note.chromatic_index() as Semitone - chord_root_pitch_class as Semitone).rem_euclid(12)
+        });
+
+        tensions
+    }
+
+    /// Whether every note of this chord belongs to `key`. See
+    /// [`Key::is_diatonic`].
+    pub fn is_diatonic_to(&self, key: &Key) -> bool {
+        key.is_diatonic(self)
+    }
+
+    /// Estimates how far a move from this chord to `other` is, where lower
+    /// values are smoother progressions. Each pitch class that is in only one
+    /// of the two chords counts 1, or 2 if it is outside `key`, and then the
+    /// number of steps between the two bass notes on the circle of fifths is
+    /// added. The distance is the same in either direction.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ChordQuality, Key, Note, NoteModifier, ScaleMode, C, F, G};
+    ///
+    /// let c_major = Key::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let c = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+    /// let g = ChordQuality::Major.to_chord(Note::new(G, 4, NoteModifier::Natural));
+    /// let f_sharp = ChordQuality::Major.to_chord(Note::new(F, 4, NoteModifier::Sharp));
+    ///
+    /// assert_eq!(c.harmonic_distance_to(&g, &c_major), 5);
+    /// assert_eq!(c.harmonic_distance_to(&f_sharp, &c_major), 15);
+    /// ```
+    pub fn harmonic_distance_to(&self, other: &Chord, key: &Key) -> u32 {
+        let key_pitch_classes = key.scale().pitch_classes();
+        let changed_pitch_classes: u32 = other
+            .pitch_classes()
+            .symmetric_difference(&self.pitch_classes())
+            .map(|pitch_class| {
+                if key_pitch_classes.contains(pitch_class) {
+                    1
+                } else {
+                    2
+                }
+            })
+            .sum();
+
+        let bass_pitch_class = |chord: &Chord| {
+            chord
+                .sort_by_pitch()
+                .notes
+                .first()
+                .map(|note| AbstractNote::from(*note).chromatic_index())
+        };
+        let fifths_apart = match (bass_pitch_class(self), bass_pitch_class(other)) {
+            (Some(from), Some(to)) => {
+                // Each semitone is seven fifths around the circle, mod 12.
+                let fifths = ((to as u32 + 12 - from as u32) * 7) % 12;
+                fifths.min(12 - fifths)
+            }
+            _ => 0,
+        };
+
+        changed_pitch_classes + fifths_apart
+    }
+
+    /// Gets the normal form of this chord's pitch classes, as used in
+    /// musical set theory. Every rotation of the sorted pitch classes is tried,
+    /// and the most compact one (the smallest span from its first to last pitch
+    /// class) is chosen. Ties are broken by picking the rotation that is packed
+    /// most tightly towards its first pitch class.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C};
+    ///
+    /// let chord = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+    /// assert_eq!(chord.to_pitch_class_normal_form(), vec![0, 4, 7]);
+    /// ```
+    pub fn to_pitch_class_normal_form(&self) -> Vec<u8> {
+        normal_form(&self.pitch_classes())
+    }
+
+    /// Gets the prime form of this chord's pitch classes, as used in musical
+    /// set theory. This is whichever of the normal form or the normal form of
+    /// its inversion is more compact, transposed to start at 0. Chords that are
+    /// inversions of each other, like major and minor triads, share a prime form.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, C};
+    ///
+    /// let chord = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+    /// assert_eq!(chord.to_pitch_class_prime_form(), vec![0, 3, 7]);
+    /// ```
+    pub fn to_pitch_class_prime_form(&self) -> Vec<u8> {
+        let pitch_classes = self.pitch_classes();
+        let inverted: BTreeSet<u8> = pitch_classes
+            .iter()
+            .map(|pitch_class| (12 - pitch_class) % 12)
+            .collect();
+
+        let transpose_to_zero = |form: Vec<u8>| -> Vec<u8> {
+            let first = form.first().copied().unwrap_or(0);
+            form.iter()
+                .map(|pitch_class| (pitch_class + 12 - first) % 12)
+                .collect()
+        };
+
+        let form = transpose_to_zero(normal_form(&pitch_classes));
+        let inverted_form = transpose_to_zero(normal_form(&inverted));
+
+        form.min(inverted_form)
+    }
+
+    /// Plays the notes of this chord one at a time, in the order given by
+    /// `direction`. Each note is played once per pass, so an up-down arpeggio
+    /// of a triad has four notes.
+    ///
+    /// ```rust
+    /// use note_lib::{ArpeggioDirection, Chord, Note, NoteModifier, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    /// let chord = c4 + e4 + g4;
+    ///
+    /// let notes: Vec<Note> = chord.arpeggiate(ArpeggioDirection::UpDown).collect();
+    /// assert_eq!(notes, vec![c4, e4, g4, e4]);
+    /// ```
+    pub fn arpeggiate(&self, direction: ArpeggioDirection) -> impl Iterator<Item = Note> {
+        arrange_arpeggio(self.sort_by_pitch().notes, direction).into_iter()
+    }
+
+    /// Arpeggiates every note of this chord's pitch classes between `lo` and
+    /// `hi` (inclusive), across as many octaves as the range spans. Pitch
+    /// classes keep the spelling of the first note in this chord that uses them.
+    ///
+    /// ```rust
+    /// use note_lib::{ArpeggioDirection, Chord, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// let notes: Vec<Note> = chord
+    ///     .arpeggiate_in_range(
+    ///         Note::new(C, 3, NoteModifier::Natural),
+    ///         Note::new(C, 5, NoteModifier::Natural),
+    ///         ArpeggioDirection::Up,
+    ///     )
+    ///     .collect();
+    /// assert_eq!(notes.len(), 7);
+    /// ```
+    pub fn arpeggiate_in_range(
+        &self,
+        lo: Note,
+        hi: Note,
+        direction: ArpeggioDirection,
+    ) -> impl Iterator<Item = Note> {
+        let lo_semitones = lo.to_semitones_from_c0();
+        let hi_semitones = hi.to_semitones_from_c0();
+
+        let mut seen_pitch_classes = BTreeSet::new();
+        let mut notes: Vec<Note> = self
+            .notes
+            .iter()
+            .filter(|note| seen_pitch_classes.insert(AbstractNote::from(**note).chromatic_index()))
+            .flat_map(|note| {
+                ((lo.octave().value() - 1)..=(hi.octave().value() + 1))
+                    .filter_map(|octave| Octave::new(octave).ok())
+                    .map(|octave| Note::new(note.raw_note(), octave, note.modifier()))
+            })
+            .filter(|candidate| {
+                (lo_semitones..=hi_semitones).contains(&candidate.to_semitones_from_c0())
+            })
+            .collect();
+        notes.sort_by_key(|note| note.to_semitones_from_c0());
+
+        arrange_arpeggio(notes, direction).into_iter()
+    }
+
+    /// Collects the notes of [`Chord::arpeggiate_in_range`] into a `Vec`, for
+    /// filling a register with chord tones.
+    ///
+    /// ```rust
+    /// use note_lib::{ArpeggioDirection, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// let notes = chord.arpeggio_in_range(
+    ///     Note::new(C, 3, NoteModifier::Natural),
+    ///     Note::new(G, 5, NoteModifier::Natural),
+    ///     ArpeggioDirection::Up,
+    /// );
+    /// assert_eq!(notes.len(), 9);
+    /// ```
+    pub fn arpeggio_in_range(&self, lo: Note, hi: Note, direction: ArpeggioDirection) -> Vec<Note> {
+        self.arpeggiate_in_range(lo, hi, direction).collect()
+    }
+
+    /// Inverts this chord by moving its lowest note up an octave `inversion`
+    /// times, or its highest note down an octave for a negative `inversion`.
+    ///
+    /// Fails if the chord is empty, or if `inversion` would move a note more
+    /// than once, which happens when it is larger than one less than the
+    /// number of notes. Also fails if a moved note would leave the range of
+    /// [`Octave`].
+    pub fn apply_inversion(&self, inversion: i8) -> Result<Chord, ChordInversionError> {
+        if self.notes.is_empty() {
+            return Err(ChordInversionError::EmptyChord);
+        }
+        if inversion.unsigned_abs() as usize >= self.notes.len() {
+            return Err(ChordInversionError::InversionOutOfRange);
+        }
+
         // When a positive inversion happens, we move the lowest note of the chord up one octave.
         // For a negative inversion for programming convenience, we just do the opposite.
         // For example, if we have a C major chord, C4 E4 G4, and we apply a positive inversion,
         // we get E4 G4 C5. If we apply a negative inversion, we get G3 C4 E4.
+        //
+        // The notes are sorted first, so the lowest and highest notes are at
+        // either end even when they weren't added in order of pitch.
 
-        let mut notes = self.notes.clone();
+        let mut notes = self.sort_by_pitch().notes;
         let mut inversion = inversion;
 
         match inversion.cmp(&0) {
@@ -42,7 +1366,11 @@ impl Chord {
                 while inversion < 0 {
                     let note = notes.remove(notes.len() - 1);
                     // Move the note down an octave.
-                    let note = Note::new(note.raw_note(), note.octave() - 1, note.modifier());
+                    let octave = note
+                        .octave()
+                        .checked_sub(1)
+                        .map_err(|_| ChordInversionError::OutOfRange)?;
+                    let note = Note::new(note.raw_note(), octave, note.modifier());
                     notes.insert(0, note);
                     inversion += 1;
                 }
@@ -52,16 +1380,90 @@ impl Chord {
                 while inversion > 0 {
                     let note = notes.remove(0);
                     // Move the note up an octave.
-                    let note = Note::new(note.raw_note(), note.octave() + 1, note.modifier());
+                    let octave = note
+                        .octave()
+                        .checked_add(1)
+                        .map_err(|_| ChordInversionError::OutOfRange)?;
+                    let note = Note::new(note.raw_note(), octave, note.modifier());
                     notes.push(note);
                     inversion -= 1
                 }
             }
         };
-        Chord::new(notes)
+        Ok(Chord::new(notes))
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoicingSimilarityError {
+    /// The chords have a different number of voices, so they can't be paired
+    /// up.
+    VoiceMismatch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordInversionError {
+    /// The chord has no notes to invert.
+    EmptyChord,
+    /// The inversion was larger than one less than the number of notes in
+    /// the chord.
+    InversionOutOfRange,
+    /// Moving a note by an octave would take it outside the range of
+    /// [`Octave`].
+    OutOfRange,
+}
+
+/// Gets the semitones a voice moves from `from` to `to`, negative when it
+/// moves down.
+fn motion_between(from: Note, to: Note) -> Semitone {
+    to.to_semitones_from_c0() - from.to_semitones_from_c0()
+}
+
+/// Orders notes that are sorted from lowest to highest pitch for an arpeggio
+/// in the given direction.
+fn arrange_arpeggio(ascending: Vec<Note>, direction: ArpeggioDirection) -> Vec<Note> {
+    let descending: Vec<Note> = ascending.iter().rev().copied().collect();
+
+    // The inner notes of the opposite pass, leaving out the turnaround notes
+    // at either end so a looped arpeggio doesn't repeat them.
+    let inner = |notes: &[Note]| -> Vec<Note> {
+        if notes.len() > 2 {
+            notes[1..notes.len() - 1].to_vec()
+        } else {
+            vec![]
+        }
+    };
+
+    match direction {
+        ArpeggioDirection::Up => ascending,
+        ArpeggioDirection::Down => descending,
+        ArpeggioDirection::UpDown => [ascending.clone(), inner(&descending)].concat(),
+        ArpeggioDirection::DownUp => [descending.clone(), inner(&ascending)].concat(),
+    }
+}
+
+/// Finds the normal form of a set of pitch classes. See
+/// [`Chord::to_pitch_class_normal_form`].
+fn normal_form(pitch_classes: &BTreeSet<u8>) -> Vec<u8> {
+    let sorted: Vec<Semitone> = pitch_classes.iter().map(|pc| *pc as Semitone).collect();
+
+    (0..sorted.len())
+        .map(|start| {
+            // Rotate, lifting the wrapped pitch classes an octave so the rotation ascends.
+            let rotation: Vec<Semitone> = sorted[start..]
+                .iter()
+                .copied()
+                .chain(sorted[..start].iter().map(|pc| pc + 12))
+                .collect();
+            let span = rotation.last().unwrap() - rotation.first().unwrap();
+            let packing: Vec<Semitone> = rotation.iter().map(|pc| pc - rotation[0]).collect();
+            (span, packing, rotation)
+        })
+        .min()
+        .map(|(_, _, rotation)| rotation.iter().map(|pc| (pc % 12) as u8).collect())
+        .unwrap_or_default()
+}
+
 impl Add for Chord {
     type Output = Self;
 
@@ -80,10 +1482,30 @@ impl Add<Note> for Chord {
     }
 }
 
+/// Lists the notes of the chord, like `[C4, E4, G4]`. The alternate form
+/// (`{:#}`) names the chord by its root and quality first, like
+/// `C Major (C4, E4, G4)`, falling back to the plain list if the quality of
+/// the chord can't be identified.
+impl Display for Chord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match self.name() {
+            Some(name) if f.alternate() => write!(f, "{} ({})", name, notes),
+            _ => write!(f, "[{}]", notes),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::{NoteModifier, B, C, E, G};
+    use crate::{NoteModifier, A, B, C, D, E, F, G};
 
     use super::*;
 
@@ -132,7 +1554,7 @@ mod tests {
             Note::new(G, 4, NoteModifier::Natural),
         ]);
 
-        let first_inversion = initial_chord.apply_inversion(1);
+        let first_inversion = initial_chord.apply_inversion(1).unwrap();
         assert_eq!(
             first_inversion.notes,
             vec![
@@ -142,7 +1564,7 @@ mod tests {
             ]
         );
 
-        let second_inversion = initial_chord.apply_inversion(2);
+        let second_inversion = initial_chord.apply_inversion(2).unwrap();
         assert_eq!(
             second_inversion.notes,
             vec![
@@ -152,7 +1574,7 @@ mod tests {
             ]
         );
 
-        let zero_inversion = initial_chord.apply_inversion(0);
+        let zero_inversion = initial_chord.apply_inversion(0).unwrap();
         assert_eq!(
             zero_inversion.notes,
             vec![
@@ -162,7 +1584,7 @@ mod tests {
             ]
         );
 
-        let negative_inversion = initial_chord.apply_inversion(-1);
+        let negative_inversion = initial_chord.apply_inversion(-1).unwrap();
         assert_eq!(
             negative_inversion.notes,
             vec![
@@ -172,4 +1594,1200 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn quality_is_identified() {
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(A, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.identify_quality(),
+            Some((
+                Note::new(C, 4, NoteModifier::Natural),
+                ChordQuality::Major6th
+            ))
+        );
+
+        let chord = Chord::new(vec![
+            Note::new(A, 3, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.identify_quality(),
+            Some((
+                Note::new(A, 3, NoteModifier::Natural),
+                ChordQuality::Minor7th
+            ))
+        );
+
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Sharp),
+            Note::new(D, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(chord.identify_quality(), None);
+    }
+
+    #[test]
+    fn shell_voicing_is_built() {
+        // Cmaj7
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(B, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.shell_voicing(),
+            Some(Chord::new(vec![
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(E, 4, NoteModifier::Natural),
+                Note::new(B, 4, NoteModifier::Natural),
+            ]))
+        );
+
+        // G7
+        let chord = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+        assert_eq!(
+            chord.shell_voicing(),
+            Some(Chord::new(vec![
+                Note::new(G, 3, NoteModifier::Natural),
+                Note::new(B, 3, NoteModifier::Natural),
+                Note::new(F, 4, NoteModifier::Natural),
+            ]))
+        );
+
+        // Dm9, where the ninth is dropped along with the fifth.
+        let chord = ChordQuality::Minor9th.to_chord(Note::new(D, 3, NoteModifier::Natural));
+        assert_eq!(
+            chord.shell_voicing(),
+            Some(Chord::new(vec![
+                Note::new(D, 3, NoteModifier::Natural),
+                Note::new(F, 3, NoteModifier::Natural),
+                Note::new(C, 4, NoteModifier::Natural),
+            ]))
+        );
+
+        // A triad has no seventh.
+        let chord = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(chord.shell_voicing(), None);
+
+        // Sus chords have no third.
+        let chord = ChordQuality::Suspended4th.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(chord.shell_voicing(), None);
+    }
+
+    #[test]
+    fn drop2_voicing_is_built() {
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(B, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.drop2_voicing(),
+            Some(Chord::new(vec![
+                Note::new(G, 3, NoteModifier::Natural),
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(E, 4, NoteModifier::Natural),
+                Note::new(B, 4, NoteModifier::Natural),
+            ]))
+        );
+
+        // G7 in second inversion: D4 F4 G4 B4
+        let chord = Chord::new(vec![
+            Note::new(D, 4, NoteModifier::Natural),
+            Note::new(F, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(B, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.drop2_voicing(),
+            Some(Chord::new(vec![
+                Note::new(G, 3, NoteModifier::Natural),
+                Note::new(D, 4, NoteModifier::Natural),
+                Note::new(F, 4, NoteModifier::Natural),
+                Note::new(B, 4, NoteModifier::Natural),
+            ]))
+        );
+
+        let chord = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(chord.drop2_voicing(), None);
+    }
+
+    #[test]
+    fn voicings_in_range_are_enumerated() {
+        let chord = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        let voicings = chord.all_voicings_in_range(
+            Note::new(C, 3, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+        );
+
+        // Every pitch class has two candidates, C3/C4, E3/E4 and G3/G4.
+        assert_eq!(voicings.len(), 8);
+        assert_eq!(
+            voicings[0],
+            Chord::new(vec![
+                Note::new(C, 3, NoteModifier::Natural),
+                Note::new(E, 3, NoteModifier::Natural),
+                Note::new(G, 3, NoteModifier::Natural),
+            ])
+        );
+        assert_eq!(
+            voicings[7],
+            Chord::new(vec![
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(E, 4, NoteModifier::Natural),
+                Note::new(G, 4, NoteModifier::Natural),
+            ])
+        );
+
+        for voicing in voicings.iter() {
+            assert_eq!(voicing.notes().len(), 3);
+            assert_eq!(voicing.pitch_classes(), chord.pitch_classes());
+            assert_eq!(voicing, &voicing.sort_by_pitch());
+        }
+
+        // A range that can't fit each pitch class.
+        let voicings = chord.all_voicings_in_range(
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+        );
+        assert!(voicings.is_empty());
+
+        // A range that's too wide to search.
+        let voicings = chord.all_voicings_in_range(
+            Note::new(C, 2, NoteModifier::Natural),
+            Note::new(C, 5, NoteModifier::Natural),
+        );
+        assert!(voicings.is_empty());
+    }
+
+    #[test]
+    fn chord_is_respelled_for_key() {
+        let g = AbstractNote::from(G);
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Flat),
+            Note::new(G, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.respell_for_key(g, ScaleMode::Ionian),
+            Chord::new(vec![
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(F, 4, NoteModifier::Sharp),
+                Note::new(G, 4, NoteModifier::Natural),
+            ])
+        );
+
+        let f = AbstractNote::from(F);
+        let chord = Chord::new(vec![
+            Note::new(A, 3, NoteModifier::Sharp),
+            Note::new(D, 4, NoteModifier::Natural),
+            Note::new(F, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.respell_for_key(f, ScaleMode::Ionian),
+            Chord::new(vec![
+                Note::new(B, 3, NoteModifier::Flat),
+                Note::new(D, 4, NoteModifier::Natural),
+                Note::new(F, 4, NoteModifier::Natural),
+            ])
+        );
+
+        // C# is not in C major, so it is spelled with sharps like the key.
+        let c = AbstractNote::from(C);
+        let chord = Chord::new(vec![
+            Note::new(A, 3, NoteModifier::Natural),
+            Note::new(D, 4, NoteModifier::Flat),
+            Note::new(E, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.respell_for_key(c, ScaleMode::Ionian),
+            Chord::new(vec![
+                Note::new(A, 3, NoteModifier::Natural),
+                Note::new(C, 4, NoteModifier::Sharp),
+                Note::new(E, 4, NoteModifier::Natural),
+            ])
+        );
+
+        // And it becomes Db in F major, which is a flat key.
+        let chord = Chord::new(vec![
+            Note::new(A, 3, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Sharp),
+            Note::new(E, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.respell_for_key(f, ScaleMode::Ionian),
+            Chord::new(vec![
+                Note::new(A, 3, NoteModifier::Natural),
+                Note::new(D, 4, NoteModifier::Flat),
+                Note::new(E, 4, NoteModifier::Natural),
+            ])
+        );
+
+        // C# major spells C as B#, which belongs to the octave below.
+        let c_sharp = AbstractNote::try_from("C#").unwrap();
+        let chord = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(F, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            chord.respell_for_key(c_sharp, ScaleMode::Ionian),
+            Chord::new(vec![
+                Note::new(B, 3, NoteModifier::Sharp),
+                Note::new(E, 4, NoteModifier::Sharp),
+            ])
+        );
+    }
+
+    fn total_movement(from: &Chord, to: &Chord) -> Semitone {
+        from.notes
+            .iter()
+            .zip(to.notes.iter())
+            .map(|(a, b)| (a.to_semitones_from_c0() - b.to_semitones_from_c0()).abs())
+            .sum()
+    }
+
+    #[test]
+    fn voices_are_led_to_target() {
+        let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        let f_major = ChordQuality::Major.to_chord(Note::new(F, 4, NoteModifier::Natural));
+
+        let voiced = c_major.voice_lead_to(&f_major);
+        assert_eq!(
+            voiced,
+            Chord::new(vec![
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(F, 4, NoteModifier::Natural),
+                Note::new(A, 4, NoteModifier::Natural),
+            ])
+        );
+        assert_eq!(voiced.pitch_classes(), f_major.pitch_classes());
+        assert_eq!(total_movement(&c_major, &voiced), 3);
+        assert!(total_movement(&c_major, &voiced) < total_movement(&c_major, &f_major));
+
+        // C major to G major keeps the common tone G.
+        let g_major = ChordQuality::Major.to_chord(Note::new(G, 4, NoteModifier::Natural));
+        let voiced = c_major.voice_lead_to(&g_major);
+        assert_eq!(
+            voiced,
+            Chord::new(vec![
+                Note::new(B, 3, NoteModifier::Natural),
+                Note::new(D, 4, NoteModifier::Natural),
+                Note::new(G, 4, NoteModifier::Natural),
+            ])
+        );
+        assert_eq!(voiced.pitch_classes(), g_major.pitch_classes());
+    }
+
+    #[test]
+    fn voices_are_led_to_target_with_different_voice_counts() {
+        let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        let g7 = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+
+        let voiced = c_major.voice_lead_to(&g7);
+        assert_eq!(voiced.notes().len(), 4);
+        assert_eq!(voiced.pitch_classes(), g7.pitch_classes());
+
+        let voiced = g7.voice_lead_to(&c_major);
+        assert_eq!(voiced.notes().len(), 4);
+        assert_eq!(voiced.pitch_classes(), c_major.pitch_classes());
+    }
+
+    #[test]
+    fn available_tensions_are_found() {
+        let c = AbstractNote::from(C);
+
+        let cmaj7 = ChordQuality::Major7th.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(
+            cmaj7.available_tensions(c, ScaleMode::Ionian),
+            vec![
+                AbstractNote::from(D),
+                AbstractNote::from(F),
+                AbstractNote::from(A)
+            ]
+        );
+
+        let g7 = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+        let tensions = g7.available_tensions(c, ScaleMode::Ionian);
+        assert_eq!(
+            tensions,
+            vec![
+                AbstractNote::from(A),
+                AbstractNote::from(C),
+                AbstractNote::from(E)
+            ]
+        );
+        assert!(tensions.contains(&AbstractNote::from(A)));
+        assert!(tensions.contains(&AbstractNote::from(E)));
+
+        // Tensions are spelled the way the scale spells them.
+        let f = AbstractNote::from(F);
+        let f_major = ChordQuality::Major.to_chord(Note::new(F, 4, NoteModifier::Natural));
+        assert_eq!(
+            f_major.available_tensions(f, ScaleMode::Ionian),
+            vec![
+                AbstractNote::from(G),
+                AbstractNote::try_from("Bb").unwrap(),
+                AbstractNote::from(D),
+                AbstractNote::from(E)
+            ]
+        );
+    }
+
+    #[test]
+    fn pitch_class_normal_form_is_found() {
+        let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(c_major.to_pitch_class_normal_form(), vec![0, 4, 7]);
+
+        // Inversions share the same normal form.
+        assert_eq!(
+            c_major
+                .apply_inversion(1)
+                .unwrap()
+                .to_pitch_class_normal_form(),
+            vec![0, 4, 7]
+        );
+
+        // G major wraps around C, G B D.
+        let g_major = ChordQuality::Major.to_chord(Note::new(G, 4, NoteModifier::Natural));
+        assert_eq!(g_major.to_pitch_class_normal_form(), vec![7, 11, 2]);
+
+        let a_minor = ChordQuality::Minor.to_chord(Note::new(A, 3, NoteModifier::Natural));
+        assert_eq!(a_minor.to_pitch_class_normal_form(), vec![9, 0, 4]);
+
+        assert_eq!(Chord::default().to_pitch_class_normal_form(), vec![]);
+    }
+
+    #[test]
+    fn pitch_class_prime_form_is_found() {
+        // Major and minor triads are both set class 3-11.
+        let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(c_major.to_pitch_class_prime_form(), vec![0, 3, 7]);
+
+        let c_minor = ChordQuality::Minor.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(c_minor.to_pitch_class_prime_form(), vec![0, 3, 7]);
+
+        let b_diminished =
+            ChordQuality::Diminished.to_chord(Note::new(B, 3, NoteModifier::Natural));
+        assert_eq!(b_diminished.to_pitch_class_prime_form(), vec![0, 3, 6]);
+
+        // Dominant and half-diminished sevenths are both set class 4-27.
+        let g7 = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+        assert_eq!(g7.to_pitch_class_prime_form(), vec![0, 2, 5, 8]);
+    }
+
+    #[test]
+    fn inversion_is_applied_to_unsorted_notes() {
+        let unsorted_chord = Chord::new(vec![
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+        ]);
+
+        assert_eq!(
+            unsorted_chord.apply_inversion(1).unwrap(),
+            Chord::new(vec![
+                Note::new(E, 4, NoteModifier::Natural),
+                Note::new(G, 4, NoteModifier::Natural),
+                Note::new(C, 5, NoteModifier::Natural),
+            ])
+        );
+
+        assert_eq!(
+            unsorted_chord.apply_inversion(-1).unwrap(),
+            Chord::new(vec![
+                Note::new(G, 3, NoteModifier::Natural),
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(E, 4, NoteModifier::Natural),
+            ])
+        );
+    }
+
+    #[test]
+    fn arpeggiates_in_each_direction() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        // Out of order, to check that arpeggios go by pitch.
+        let chord = Chord::new(vec![g4, c4, e4]);
+
+        let arpeggio = |direction| chord.arpeggiate(direction).collect::<Vec<Note>>();
+
+        assert_eq!(arpeggio(ArpeggioDirection::Up), vec![c4, e4, g4]);
+        assert_eq!(arpeggio(ArpeggioDirection::Down), vec![g4, e4, c4]);
+        assert_eq!(arpeggio(ArpeggioDirection::UpDown), vec![c4, e4, g4, e4]);
+        assert_eq!(arpeggio(ArpeggioDirection::DownUp), vec![g4, e4, c4, e4]);
+
+        // With two notes there is nothing between the turnarounds.
+        let dyad = c4 + g4;
+        assert_eq!(dyad.arpeggiate(ArpeggioDirection::UpDown).count(), 2);
+        assert_eq!(
+            Chord::default()
+                .arpeggiate(ArpeggioDirection::DownUp)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn arpeggiates_in_range() {
+        let chord = Note::new(C, 4, NoteModifier::Natural)
+            + Note::new(E, 4, NoteModifier::Natural)
+            + Note::new(G, 4, NoteModifier::Natural);
+        let lo = Note::new(E, 3, NoteModifier::Natural);
+        let hi = Note::new(E, 5, NoteModifier::Natural);
+
+        let names = |direction| {
+            chord
+                .arpeggiate_in_range(lo, hi, direction)
+                .map(|note| note.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            names(ArpeggioDirection::Up),
+            vec!["E3", "G3", "C4", "E4", "G4", "C5", "E5"]
+        );
+        assert_eq!(
+            names(ArpeggioDirection::UpDown),
+            vec!["E3", "G3", "C4", "E4", "G4", "C5", "E5", "C5", "G4", "E4", "C4", "G3"]
+        );
+        assert_eq!(names(ArpeggioDirection::Down).len(), 7);
+
+        let notes: Vec<String> = chord
+            .arpeggiate_in_range(
+                Note::new(C, 3, NoteModifier::Natural),
+                Note::new(G, 5, NoteModifier::Natural),
+                ArpeggioDirection::Up,
+            )
+            .map(|note| note.to_string())
+            .collect();
+        assert_eq!(
+            notes,
+            vec!["C3", "E3", "G3", "C4", "E4", "G4", "C5", "E5", "G5"]
+        );
+    }
+
+    #[test]
+    fn collects_arpeggio_in_range() {
+        let chord = Note::new(C, 4, NoteModifier::Natural)
+            + Note::new(E, 4, NoteModifier::Natural)
+            + Note::new(G, 4, NoteModifier::Natural);
+        let lo = Note::new(C, 3, NoteModifier::Natural);
+        let hi = Note::new(G, 5, NoteModifier::Natural);
+
+        let names = |direction| {
+            chord
+                .arpeggio_in_range(lo, hi, direction)
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        assert_eq!(
+            names(ArpeggioDirection::Up),
+            vec!["C3", "E3", "G3", "C4", "E4", "G4", "C5", "E5", "G5"]
+        );
+        assert_eq!(
+            names(ArpeggioDirection::UpDown),
+            vec![
+                "C3", "E3", "G3", "C4", "E4", "G4", "C5", "E5", "G5", "E5", "C5", "G4", "E4", "C4",
+                "G3", "E3"
+            ]
+        );
+        assert_eq!(
+            chord.arpeggio_in_range(lo, hi, ArpeggioDirection::Down),
+            chord
+                .arpeggiate_in_range(lo, hi, ArpeggioDirection::Down)
+                .collect::<Vec<Note>>()
+        );
+    }
+
+    #[test]
+    fn creates_chord_from_midi_notes() {
+        let chord = Chord::from_midi_notes(&[60, 64, 67], ModifierPreference::Sharp);
+        assert_eq!(
+            chord.notes,
+            vec![
+                Note::new(C, 4, NoteModifier::Natural),
+                Note::new(E, 4, NoteModifier::Natural),
+                Note::new(G, 4, NoteModifier::Natural),
+            ]
+        );
+        assert_eq!(
+            chord.identify_quality(),
+            Some((Note::new(C, 4, NoteModifier::Natural), ChordQuality::Major))
+        );
+
+        let chord = Chord::from_midi_notes(&[61, 64, 68], ModifierPreference::Sharp);
+        assert_eq!(
+            chord.identify_quality(),
+            Some((Note::new(C, 4, NoteModifier::Sharp), ChordQuality::Minor))
+        );
+
+        let chord = Chord::from_midi_notes(&[60, 63, 67], ModifierPreference::Flat);
+        assert_eq!(chord.notes[1], Note::new(E, 4, NoteModifier::Flat));
+        assert_eq!(
+            chord.identify_quality().map(|(_, quality)| quality),
+            Some(ChordQuality::Minor)
+        );
+
+        let chord = Chord::from_midi_notes(&[60, 200, 67], ModifierPreference::Sharp);
+        assert_eq!(chord.notes.len(), 2);
+    }
+
+    #[test]
+    fn harmonic_distance_is_measured() {
+        let c_major = Key::new(AbstractNote::from(C), ScaleMode::Ionian);
+        let triad =
+            |raw_note, modifier| ChordQuality::Major.to_chord(Note::new(raw_note, 4, modifier));
+        let c = triad(C, NoteModifier::Natural);
+        let g = triad(G, NoteModifier::Natural);
+        let f_sharp = triad(F, NoteModifier::Sharp);
+
+        assert!(c.is_diatonic_to(&c_major));
+        assert!(g.is_diatonic_to(&c_major));
+        assert!(!f_sharp.is_diatonic_to(&c_major));
+
+        assert_eq!(c.harmonic_distance_to(&c, &c_major), 0);
+
+        // G major swaps C and E for B and D, and G is a fifth from C.
+        assert_eq!(c.harmonic_distance_to(&g, &c_major), 5);
+        assert_eq!(g.harmonic_distance_to(&c, &c_major), 5);
+        assert_eq!(
+            c.harmonic_distance_to(&triad(F, NoteModifier::Natural), &c_major),
+            5
+        );
+
+        // F# major shares nothing with C major, its F#, A# and C# are outside
+        // the key, and F# is a tritone from C.
+        assert_eq!(c.harmonic_distance_to(&f_sharp, &c_major), 15);
+        assert_eq!(f_sharp.harmonic_distance_to(&c, &c_major), 15);
+
+        // C major to Eb major swaps C and E for Eb and Bb. Eb and Bb are
+        // outside C major, but only E is outside Eb major.
+        let e_flat = triad(E, NoteModifier::Flat);
+        let e_flat_major = Key::new(AbstractNote::try_from("Eb").unwrap(), ScaleMode::Ionian);
+        assert_eq!(c.harmonic_distance_to(&e_flat, &c_major), 9);
+        assert_eq!(c.harmonic_distance_to(&e_flat, &e_flat_major), 8);
+
+        // A C major 7th adds only B, with the same bass.
+        let c_major_7th = ChordQuality::Major7th.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(c.harmonic_distance_to(&c_major_7th, &c_major), 1);
+        assert_eq!(c_major_7th.harmonic_distance_to(&c, &c_major), 1);
+        assert_eq!(c.harmonic_distance_to(&Chord::default(), &c_major), 3);
+    }
+
+    #[test]
+    fn chord_is_displayed() {
+        let c_major = ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural));
+        assert_eq!(format!("{}", c_major), "[C4, E4, G4]");
+        assert_eq!(format!("{:#}", c_major), "C Major (C4, E4, G4)");
+
+        let first_inversion = c_major.apply_inversion(1).unwrap();
+        assert_eq!(format!("{}", first_inversion), "[E4, G4, C5]");
+        assert_eq!(format!("{:#}", first_inversion), "C Major (E4, G4, C5)");
+
+        let cluster = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Sharp),
+            Note::new(D, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(format!("{}", cluster), "[C4, C#4, D4]");
+        assert_eq!(format!("{:#}", cluster), "[C4, C#4, D4]");
+
+        assert_eq!(format!("{:#}", Chord::default()), "[]");
+    }
+
+    #[test]
+    fn set_operations_are_applied() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let b4 = Note::new(B, 4, NoteModifier::Natural);
+        let c5 = Note::new(C, 5, NoteModifier::Natural);
+
+        // Overlapping
+        let c_major = c4 + e4 + g4;
+        let e_minor = e4 + g4 + b4;
+        assert_eq!(c_major.union(&e_minor), c4 + e4 + g4 + b4);
+        assert_eq!(c_major.intersection(&e_minor), e4 + g4);
+        assert_eq!(
+            c_major.pitch_class_intersection(&e_minor),
+            BTreeSet::from([4, 7])
+        );
+
+        // Disjoint
+        let upper = b4 + c5;
+        assert_eq!((c4 + e4).union(&upper), c4 + e4 + b4 + c5);
+        assert_eq!((c4 + e4).intersection(&upper), Chord::default());
+        // C4 and C5 are different notes, but the same pitch class.
+        assert_eq!(
+            (c4 + e4).pitch_class_intersection(&upper),
+            BTreeSet::from([0])
+        );
+
+        // Identical
+        assert_eq!(c_major.union(&c_major), c_major);
+        assert_eq!(c_major.intersection(&c_major), c_major);
+        assert_eq!(
+            c_major.pitch_class_intersection(&c_major),
+            c_major.pitch_classes()
+        );
+    }
+
+    #[test]
+    fn spread_chords_are_compacted() {
+        let c2 = Note::new(C, 2, NoteModifier::Natural);
+        let e3 = Note::new(E, 3, NoteModifier::Natural);
+        let g5 = Note::new(G, 5, NoteModifier::Natural);
+        let b6 = Note::new(B, 6, NoteModifier::Natural);
+        let c6 = Note::new(C, 6, NoteModifier::Natural);
+
+        let spread = g5 + c2 + b6 + e3 + c6;
+        assert_eq!(
+            spread.compress_to_single_octave().to_string(),
+            "[C4, E4, G4, B4]"
+        );
+        assert_eq!(spread.close_position().to_string(), "[C2, E2, G2, C3, B3]");
+
+        // A first inversion keeps its bass note.
+        let first_inversion = e3 + g5 + c6;
+        assert_eq!(first_inversion.close_position().to_string(), "[E3, G3, C4]");
+        assert_eq!(
+            first_inversion.compress_to_single_octave().to_string(),
+            "[C4, E4, G4]"
+        );
+
+        assert_eq!(Chord::default().close_position(), Chord::default());
+        assert_eq!(
+            Chord::default().compress_to_single_octave(),
+            Chord::default()
+        );
+    }
+
+    #[test]
+    fn notes_are_accessed_by_position() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let chord = g4 + c4 + e4;
+
+        assert_eq!(chord.note_at_position(0), Some(g4));
+        assert_eq!(chord.note_at_position(2), Some(e4));
+        assert_eq!(chord.note_at_position(3), None);
+
+        assert_eq!(chord.swap_notes(0, 1), Some(c4 + g4 + e4));
+        assert_eq!(chord.swap_notes(1, 1), Some(chord.clone()));
+        assert_eq!(chord.swap_notes(5, 1), None);
+        assert_eq!(Chord::default().swap_notes(0, 0), None);
+    }
+
+    #[test]
+    fn reduces_to_triads() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let c_major = ChordQuality::Major.to_chord(c4);
+
+        assert_eq!(
+            ChordQuality::Major7th.to_chord(c4).reduce_to_triadic_form(),
+            Some(c_major.clone())
+        );
+        assert_eq!(
+            ChordQuality::Major13th
+                .to_chord(c4)
+                .reduce_to_triadic_form(),
+            Some(c_major.clone())
+        );
+        assert_eq!(
+            ChordQuality::Minor11th
+                .to_chord(c4)
+                .reduce_to_triadic_form(),
+            Some(ChordQuality::Minor.to_chord(c4))
+        );
+        assert_eq!(
+            ChordQuality::Diminished7th
+                .to_chord(c4)
+                .reduce_to_triadic_form(),
+            Some(ChordQuality::Diminished.to_chord(c4))
+        );
+
+        // An inverted dominant 7th keeps the lowest note of each pitch class.
+        let g7_inverted = Chord::new(vec![
+            Note::new(B, 3, NoteModifier::Natural),
+            Note::new(F, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(D, 5, NoteModifier::Natural),
+        ]);
+        assert_eq!(
+            g7_inverted.reduce_to_triadic_form(),
+            Some(Chord::new(vec![
+                Note::new(B, 3, NoteModifier::Natural),
+                Note::new(G, 4, NoteModifier::Natural),
+                Note::new(D, 5, NoteModifier::Natural),
+            ]))
+        );
+
+        let cluster =
+            c4 + Note::new(C, 4, NoteModifier::Sharp) + Note::new(D, 4, NoteModifier::Natural);
+        assert_eq!(cluster.reduce_to_triadic_form(), None);
+    }
+
+    #[test]
+    fn checks_chords_are_complete() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+
+        assert!(!(c4 + e4).is_complete_for_quality(ChordQuality::Major));
+        assert!((c4 + e4 + g4).is_complete_for_quality(ChordQuality::Major));
+        assert!(!(c4 + e4 + g4).is_complete_for_quality(ChordQuality::Major7th));
+        assert!(ChordQuality::Major9th
+            .to_chord(c4)
+            .is_complete_for_quality(ChordQuality::Major7th));
+
+        // The quality is built on the bass note, not the root.
+        assert!(!(e4 + g4 + Note::new(C, 5, NoteModifier::Natural))
+            .is_complete_for_quality(ChordQuality::Major));
+        assert!(!Chord::default().is_complete_for_quality(ChordQuality::Major));
+    }
+
+    #[test]
+    fn moves_bass_notes() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let c_major = c4 + e4 + g4;
+
+        let e3 = Note::new(E, 3, NoteModifier::Natural);
+        assert_eq!(c_major.with_bass(e3), e3 + c4 + g4);
+
+        // A bass note that isn't in the chord is added.
+        let a2 = Note::new(A, 2, NoteModifier::Natural);
+        assert_eq!(c_major.with_bass(a2), Chord::new(vec![a2, c4, e4, g4]));
+
+        // An existing note keeps its spelling.
+        let f_flat3 = Note::new(F, 3, NoteModifier::Flat);
+        assert_eq!(c_major.with_bass(f_flat3), e3 + c4 + g4);
+    }
+
+    #[test]
+    fn names_chords_in_slash_notation() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e3 = Note::new(E, 3, NoteModifier::Natural);
+        let g3 = Note::new(G, 3, NoteModifier::Natural);
+        let a3 = Note::new(A, 3, NoteModifier::Natural);
+
+        let c_major = ChordQuality::Major.to_chord(c4);
+        assert_eq!(c_major.slash_notation(), Some("C".to_string()));
+        assert_eq!(
+            c_major.with_bass(e3).slash_notation(),
+            Some("C/E".to_string())
+        );
+        assert_eq!(
+            c_major.with_bass(g3).slash_notation(),
+            Some("C/G".to_string())
+        );
+
+        let a_minor_7 = ChordQuality::Minor7th.to_chord(a3);
+        assert_eq!(a_minor_7.slash_notation(), Some("Am7".to_string()));
+        // Am7 over C is spelled the same as C6, which is preferred.
+        assert_eq!(
+            a_minor_7
+                .with_bass(Note::new(C, 3, NoteModifier::Natural))
+                .slash_notation(),
+            Some("Cmaj6".to_string())
+        );
+        assert_eq!(
+            ChordQuality::Minor
+                .to_chord(a3)
+                .with_bass(Note::new(C, 3, NoteModifier::Natural))
+                .slash_notation(),
+            Some("Am/C".to_string())
+        );
+
+        let cluster =
+            c4 + Note::new(C, 4, NoteModifier::Sharp) + Note::new(D, 4, NoteModifier::Natural);
+        assert_eq!(cluster.slash_notation(), None);
+    }
+
+    #[test]
+    fn finds_chord_registers() {
+        let c2 = Note::new(C, 2, NoteModifier::Natural);
+        let e2 = Note::new(E, 2, NoteModifier::Natural);
+        let g2 = Note::new(G, 2, NoteModifier::Natural);
+        assert_eq!((c2 + e2 + g2).register(), Some(ChordRegister::Bass));
+
+        // A wide chord is placed by its average pitch.
+        let c6 = Note::new(C, 6, NoteModifier::Natural);
+        assert_eq!((c2 + c6).register(), Some(ChordRegister::Tenor));
+
+        assert_eq!(Chord::default().register(), None);
+    }
+
+    #[test]
+    fn counts_voices_by_register() {
+        let chord = Chord::new(vec![
+            Note::new(C, 1, NoteModifier::Natural),
+            Note::new(G, 2, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(G, 4, NoteModifier::Natural),
+            Note::new(C, 5, NoteModifier::Natural),
+        ]);
+
+        let counts = chord.count_voices_by_register();
+        assert_eq!(counts[&ChordRegister::SubBass], 1);
+        assert_eq!(counts[&ChordRegister::Bass], 1);
+        assert_eq!(counts[&ChordRegister::Baritone], 0);
+        assert_eq!(counts[&ChordRegister::Tenor], 0);
+        assert_eq!(counts[&ChordRegister::Alto], 2);
+        assert_eq!(counts[&ChordRegister::Soprano], 1);
+        assert_eq!(counts.values().sum::<usize>(), chord.notes().len());
+    }
+
+    #[test]
+    fn finds_guitar_voicings() {
+        let tuning = standard_guitar_tuning();
+
+        let e_major = ChordQuality::Major.to_chord(Note::new(E, 4, NoteModifier::Natural));
+        assert_eq!(
+            e_major.guitar_voicing_in_position(tuning, 0, 0),
+            Some(vec![Some(0), Some(2), Some(2), Some(1), Some(0), Some(0)])
+        );
+
+        let a_minor = ChordQuality::Minor.to_chord(Note::new(A, 3, NoteModifier::Natural));
+        assert_eq!(
+            a_minor.guitar_voicing_in_position(tuning, 0, 0),
+            Some(vec![None, Some(0), Some(2), Some(2), Some(1), Some(0)])
+        );
+
+        // With a capo on the 2nd fret, the E major shape plays F# major.
+        let f_sharp_major = ChordQuality::Major.to_chord(Note::new(F, 3, NoteModifier::Sharp));
+        assert_eq!(
+            f_sharp_major.guitar_voicing_in_position(tuning, 2, 0),
+            Some(vec![Some(2), Some(4), Some(4), Some(3), Some(2), Some(2)])
+        );
+
+        // A chord can't be voiced when its notes don't fit in the position.
+        let cluster = Chord::new(vec![
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Sharp),
+            Note::new(D, 4, NoteModifier::Natural),
+            Note::new(D, 4, NoteModifier::Sharp),
+            Note::new(E, 4, NoteModifier::Natural),
+            Note::new(F, 4, NoteModifier::Natural),
+        ]);
+        assert_eq!(cluster.guitar_voicing_in_position(tuning, 0, 0), None);
+        assert_eq!(
+            Chord::default().guitar_voicing_in_position(tuning, 0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn guitar_voicings_stop_at_the_highest_fret() {
+        let tuning = standard_guitar_tuning();
+        let e_major = ChordQuality::Major.to_chord(Note::new(E, 4, NoteModifier::Natural));
+
+        // 240 frets is 20 octaves, so the open E shape still fits.
+        assert_eq!(
+            e_major.guitar_voicing_in_position(tuning, 240, 0),
+            Some(vec![
+                Some(240),
+                Some(242),
+                Some(242),
+                Some(241),
+                Some(240),
+                Some(240)
+            ])
+        );
+
+        assert_eq!(e_major.guitar_voicing_in_position(tuning, 200, 60), None);
+        assert_eq!(e_major.guitar_voicing_in_position(tuning, 0, 253), None);
+        assert_eq!(e_major.guitar_voicing_in_position(tuning, 255, 0), None);
+        assert_eq!(e_major.guitar_voicing_in_position(tuning, 255, 255), None);
+    }
+
+    #[test]
+    fn extends_with_quality() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let b4 = Note::new(B, 4, NoteModifier::Natural);
+        let d5 = Note::new(D, 5, NoteModifier::Natural);
+
+        let triad = c4 + e4 + g4;
+        assert_eq!(
+            triad.extend_with_quality(ChordQuality::Major7th),
+            Ok(c4 + e4 + g4 + b4)
+        );
+        assert_eq!(
+            triad.extend_with_quality(ChordQuality::Major9th),
+            Ok(c4 + e4 + g4 + b4 + d5)
+        );
+
+        // Pitch classes already in the chord aren't doubled, whatever octave
+        // they're in.
+        let open =
+            Note::new(C, 3, NoteModifier::Natural) + g4 + Note::new(E, 5, NoteModifier::Natural);
+        let extended = open.extend_with_quality(ChordQuality::Dominant7th).unwrap();
+        assert_eq!(extended.notes().len(), 4);
+        assert_eq!(extended.pitch_classes().len(), 4);
+
+        assert_eq!(
+            Chord::default().extend_with_quality(ChordQuality::Major),
+            Ok(Chord::default())
+        );
+
+        // Near the top of the range, extensions that would pass B9 are an
+        // error instead of a panic.
+        let b8 = Note::new(B, 8, NoteModifier::Natural);
+        let high_triad = ChordQuality::Major.to_chord(b8);
+        assert!(matches!(
+            high_triad.extend_with_quality(ChordQuality::Major13th),
+            Err(NoteRangeError::TooHigh(_))
+        ));
+        assert_eq!(
+            high_triad.extend_with_quality(ChordQuality::Major7th),
+            Ok(ChordQuality::Major7th.to_chord(b8))
+        );
+    }
+
+    #[test]
+    fn names_chords() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let b4 = Note::new(B, 4, NoteModifier::Natural);
+        let c5 = Note::new(C, 5, NoteModifier::Natural);
+
+        let c_major_7th = c4 + e4 + g4 + b4;
+        assert_eq!(c_major_7th.name(), Some("C Major 7th".to_string()));
+        assert_eq!(c_major_7th.short_name(), Some("Cmaj7".to_string()));
+
+        let first_inversion = e4 + g4 + c5;
+        assert_eq!(first_inversion.name(), Some("C Major".to_string()));
+        assert_eq!(first_inversion.short_name(), Some("C/E".to_string()));
+
+        let cluster =
+            c4 + Note::new(C, 4, NoteModifier::Sharp) + Note::new(D, 4, NoteModifier::Natural);
+        assert_eq!(cluster.name(), None);
+        assert_eq!(cluster.short_name(), None);
+    }
+
+    #[test]
+    fn rejects_impossible_inversions() {
+        let c_major = Note::new(C, 4, NoteModifier::Natural)
+            + Note::new(E, 4, NoteModifier::Natural)
+            + Note::new(G, 4, NoteModifier::Natural);
+
+        assert!(c_major.apply_inversion(2).is_ok());
+        assert!(c_major.apply_inversion(-2).is_ok());
+        assert_eq!(
+            c_major.apply_inversion(3),
+            Err(ChordInversionError::InversionOutOfRange)
+        );
+        assert_eq!(
+            c_major.apply_inversion(-3),
+            Err(ChordInversionError::InversionOutOfRange)
+        );
+        assert_eq!(
+            Chord::default().apply_inversion(0),
+            Err(ChordInversionError::EmptyChord)
+        );
+
+        let triad_at = |octave: i32| {
+            Note::new(C, octave, NoteModifier::Natural)
+                + Note::new(E, octave, NoteModifier::Natural)
+                + Note::new(G, octave, NoteModifier::Natural)
+        };
+        assert_eq!(
+            triad_at(Octave::MAX).apply_inversion(1),
+            Err(ChordInversionError::OutOfRange)
+        );
+        assert!(triad_at(Octave::MAX).apply_inversion(-1).is_ok());
+        assert_eq!(
+            triad_at(Octave::MIN).apply_inversion(-1),
+            Err(ChordInversionError::OutOfRange)
+        );
+        assert!(triad_at(Octave::MIN).apply_inversion(1).is_ok());
+    }
+
+    #[test]
+    fn sorts_by_pitch_in_place() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g3 = Note::new(G, 3, NoteModifier::Natural);
+
+        let mut chord = c4 + e4 + g3;
+        chord.sort_by_pitch_mut();
+        assert_eq!(chord, g3 + c4 + e4);
+    }
+
+    #[test]
+    fn measures_outer_voice_motion() {
+        let chord = |notes: &[(RawNote, i32)]| {
+            Chord::new(
+                notes
+                    .iter()
+                    .map(|(raw_note, octave)| Note::new(*raw_note, *octave, NoteModifier::Natural))
+                    .collect(),
+            )
+        };
+        let c_major = chord(&[(G, 4), (C, 4), (E, 4)]);
+        let d_minor = chord(&[(D, 4), (F, 4), (A, 4)]);
+        let g_major = chord(&[(G, 3), (B, 3), (D, 4), (G, 4)]);
+
+        // Up from C major, and back down.
+        assert_eq!(c_major.soprano_motion_to(&d_minor), Some(2));
+        assert_eq!(d_minor.bass_motion_to(&c_major), Some(-2));
+        assert_eq!(c_major.bass_motion_to(&g_major), Some(-5));
+        assert_eq!(c_major.soprano_motion_to(&g_major), Some(0));
+
+        // The same distance either way, but the direction tells them apart.
+        let c4_bass = chord(&[(C, 4), (E, 4), (G, 4)]);
+        let a3_bass = chord(&[(A, 3), (C, 4), (E, 4)]);
+        assert_eq!(c4_bass.bass_motion_to(&a3_bass), Some(-3));
+        assert_eq!(a3_bass.bass_motion_to(&c4_bass), Some(3));
+
+        assert_eq!(
+            c_major.soprano_note(),
+            Some(Note::new(G, 4, NoteModifier::Natural))
+        );
+        assert_eq!(
+            c_major.bass_note(),
+            Some(Note::new(C, 4, NoteModifier::Natural))
+        );
+        assert_eq!(c_major.bass_motion_to(&Chord::default()), None);
+        assert_eq!(Chord::default().soprano_motion_to(&c_major), None);
+    }
+
+    #[test]
+    fn classifies_open_and_close_voicings() {
+        let c3 = Note::new(C, 3, NoteModifier::Natural);
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let c5 = Note::new(C, 5, NoteModifier::Natural);
+
+        assert!((c4 + e4 + g4).is_close_voicing());
+        assert!(!(c4 + e4 + g4).is_open_voicing());
+
+        assert!((c3 + e4 + g4).is_open_voicing());
+        assert!(!(c3 + e4 + g4).is_close_voicing());
+
+        // An octave from bass to soprano still counts as close.
+        assert!((c4 + e4 + g4 + c5).is_close_voicing());
+
+        assert!(!Chord::default().is_close_voicing());
+        assert!(!Chord::default().is_open_voicing());
+    }
+
+    #[test]
+    fn omits_notes_by_interval() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let b4 = Note::new(B, 4, NoteModifier::Natural);
+        let c_major_7th = c4 + e4 + g4 + b4;
+
+        assert_eq!(
+            c_major_7th.omit_note_by_interval(SimpleInterval::PerfectFifth),
+            Some(c4 + e4 + b4)
+        );
+        assert_eq!(
+            c_major_7th.omit_note_by_interval(SimpleInterval::MajorThird),
+            Some(c4 + g4 + b4)
+        );
+        assert_eq!(
+            c_major_7th.omit_note_by_interval(SimpleInterval::MinorSeventh),
+            None
+        );
+
+        // Doubled notes are all removed, and an inversion is still measured
+        // from the root.
+        let g3 = Note::new(G, 3, NoteModifier::Natural);
+        assert_eq!(
+            (g3 + c4 + e4 + g4).omit_note_by_interval(SimpleInterval::PerfectFifth),
+            Some(c4 + e4)
+        );
+
+        let cluster =
+            c4 + Note::new(C, 4, NoteModifier::Sharp) + Note::new(D, 4, NoteModifier::Natural);
+        assert_eq!(
+            cluster.omit_note_by_interval(SimpleInterval::PerfectUnison),
+            None
+        );
+    }
+
+    #[test]
+    fn counts_voices() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+
+        let empty = Chord::new(vec![]);
+        assert_eq!(empty.num_voices(), 0);
+        assert!(empty.is_empty());
+
+        let dyad = c4 + e4;
+        assert!(dyad.is_dyad());
+        assert!(!dyad.is_empty());
+
+        let cases = [
+            (ChordQuality::Major, 3),
+            (ChordQuality::Dominant7th, 4),
+            (ChordQuality::Major9th, 5),
+            (ChordQuality::Minor13th, 7),
+        ];
+        for (quality, voices) in cases {
+            let chord = quality.to_chord(c4);
+            assert_eq!(chord.num_voices(), voices);
+            assert_eq!(chord.is_dyad(), voices == 2);
+            assert_eq!(chord.is_triad(), voices == 3);
+            assert_eq!(chord.is_tetrad(), voices == 4);
+            assert_eq!(chord.is_extended(), voices >= 5);
+        }
+    }
+
+    #[test]
+    fn scores_voicing_similarity() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let e4 = Note::new(E, 4, NoteModifier::Natural);
+        let g4 = Note::new(G, 4, NoteModifier::Natural);
+        let c_major = c4 + e4 + g4;
+
+        assert_eq!(c_major.voicing_similarity(&c_major), Ok(1.0));
+
+        // The order the notes are given in doesn't matter.
+        assert_eq!(c_major.voicing_similarity(&(g4 + c4 + e4)), Ok(1.0));
+
+        // C E G to C F A moves the upper voices by one and two semitones.
+        let f_major =
+            c4 + Note::new(F, 4, NoteModifier::Natural) + Note::new(A, 4, NoteModifier::Natural);
+        assert_eq!(c_major.voicing_similarity(&f_major), Ok(1.0 - 3.0 / 36.0));
+        assert_eq!(
+            f_major.voicing_similarity(&c_major),
+            c_major.voicing_similarity(&f_major)
+        );
+
+        // Moving every voice an octave or more is as different as it gets.
+        let far = ChordQuality::Major.to_chord(Note::new(C, 6, NoteModifier::Natural));
+        assert_eq!(c_major.voicing_similarity(&far), Ok(0.0));
+
+        assert_eq!(
+            c_major.voicing_similarity(&(c4 + e4)),
+            Err(VoicingSimilarityError::VoiceMismatch)
+        );
+        assert_eq!(
+            Chord::default().voicing_similarity(&Chord::default()),
+            Ok(1.0)
+        );
+    }
 }
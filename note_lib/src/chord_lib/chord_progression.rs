@@ -0,0 +1,365 @@
+use std::collections::BTreeSet;
+
+use super::{Chord, ChordQuality};
+use crate::{AbstractNote, Key, ModifierPreference, Note, NoteModifier, ScaleMode, SimpleInterval};
+
+/// The octave that [`ChordProgression::voice_lead`] starts its first chord in.
+const VOICE_LEADING_START_OCTAVE: i32 = 3;
+
+/// The share of a progression's pitch classes that must be in a key for
+/// [`ChordProgression::detect_key`] to pick it.
+const DETECT_KEY_MIN_DIATONIC_RATIO: f64 = 0.7;
+
+/// A sequence of chords, played one after another.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ChordProgression {
+    chords: Vec<Chord>,
+}
+
+impl ChordProgression {
+    pub fn new(chords: Vec<Chord>) -> Self {
+        ChordProgression { chords }
+    }
+
+    pub fn chords(&self) -> &[Chord] {
+        &self.chords
+    }
+
+    /// Voices this progression so each chord moves as little as possible from
+    /// the one before it. The first chord is put in close position with its
+    /// bass in octave 3, and every following chord is voiced with
+    /// [`Chord::voice_lead_to`] from the previous one.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordProgression, ChordQuality, Note, NoteModifier, C, F};
+    ///
+    /// let progression = ChordProgression::new(vec![
+    ///     ChordQuality::Major.to_chord(Note::new(C, 4, NoteModifier::Natural)),
+    ///     ChordQuality::Major.to_chord(Note::new(F, 4, NoteModifier::Natural)),
+    /// ]);
+    ///
+    /// let voiced = progression.voice_lead();
+    /// assert_eq!(voiced.chords()[0].to_string(), "[C3, E3, G3]");
+    /// assert_eq!(voiced.chords()[1].to_string(), "[C3, F3, A3]");
+    /// ```
+    pub fn voice_lead(&self) -> ChordProgression {
+        let Some(first) = self.chords.first() else {
+            return ChordProgression::default();
+        };
+
+        let close = first.close_position();
+        let octave_shift = match close.notes().first() {
+            Some(bass) => VOICE_LEADING_START_OCTAVE - bass.octave().value(),
+            None => 0,
+        };
+        let starting_voicing = Chord::new(
+            close
+                .notes()
+                .iter()
+                .map(|note| AbstractNote::from(*note).to_note(note.octave() + octave_shift))
+                .collect(),
+        );
+
+        self.voice_lead_from(starting_voicing)
+    }
+
+    /// Voices this progression like [`ChordProgression::voice_lead`], but uses
+    /// `starting_voicing` in place of the first chord.
+    pub fn voice_lead_from(&self, starting_voicing: Chord) -> ChordProgression {
+        if self.chords.is_empty() {
+            return ChordProgression::default();
+        }
+
+        let mut chords = vec![starting_voicing];
+        for chord in self.chords.iter().skip(1) {
+            let voiced = chords.last().unwrap().voice_lead_to(chord);
+            chords.push(voiced);
+        }
+
+        ChordProgression::new(chords)
+    }
+
+    /// Guesses the major or minor key of this progression, by counting how
+    /// many of the pitch classes used across all of its chords are in each
+    /// key. Returns `None` if the progression is empty, or if fewer than 70%
+    /// of its pitch classes fit any key.
+    ///
+    /// When keys fit equally well, the key with fewer sharps or flats wins,
+    /// then the key whose tonic is the bass of the last chord, then major
+    /// keys over minor ones.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ChordProgression, ChordQuality, Note, NoteModifier, ScaleMode, C, D, G};
+    ///
+    /// let progression = ChordProgression::new(vec![
+    ///     ChordQuality::Minor7th.to_chord(Note::new(D, 3, NoteModifier::Natural)),
+    ///     ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural)),
+    ///     ChordQuality::Major7th.to_chord(Note::new(C, 3, NoteModifier::Natural)),
+    /// ]);
+    ///
+    /// let key = progression.detect_key().unwrap();
+    /// assert_eq!(key.root(), AbstractNote::from(C));
+    /// assert_eq!(key.mode(), ScaleMode::Ionian);
+    /// ```
+    pub fn detect_key(&self) -> Option<Key> {
+        let pitch_classes: BTreeSet<u8> = self
+            .chords
+            .iter()
+            .flat_map(|chord| chord.pitch_classes())
+            .collect();
+        if pitch_classes.is_empty() {
+            return None;
+        }
+
+        let final_bass = self
+            .chords
+            .last()
+            .and_then(|chord| chord.sort_by_pitch().notes().first().copied())
+            .map(|bass| AbstractNote::from(bass).chromatic_index());
+
+        // Both spellings of each black key, so that the simpler of keys like
+        // Db and C# major can win.
+        let mut tonics: Vec<AbstractNote> = vec![];
+        for tonic in AbstractNote::all_chromatic(ModifierPreference::Sharp)
+            .into_iter()
+            .chain(AbstractNote::all_chromatic(ModifierPreference::Flat))
+        {
+            if !tonics.contains(&tonic) {
+                tonics.push(tonic);
+            }
+        }
+
+        let (key, diatonic_count) = [ScaleMode::Ionian, ScaleMode::Aeolian]
+            .into_iter()
+            .flat_map(|mode| tonics.iter().map(move |tonic| Key::new(*tonic, mode)))
+            .map(|key| {
+                let scale = key.scale();
+                let diatonic_count = pitch_classes.intersection(&scale.pitch_classes()).count();
+                let accidentals = scale
+                    .all_notes()
+                    .iter()
+                    .filter(|note| note.modifier != NoteModifier::Natural)
+                    .count();
+                let ends_on_tonic = final_bass == Some(key.root().chromatic_index());
+
+                (key, diatonic_count, accidentals, ends_on_tonic)
+            })
+            .min_by_key(|(key, diatonic_count, accidentals, ends_on_tonic)| {
+                (
+                    std::cmp::Reverse(*diatonic_count),
+                    *accidentals,
+                    !ends_on_tonic,
+                    key.mode() != ScaleMode::Ionian,
+                )
+            })
+            .map(|(key, diatonic_count, ..)| (key, diatonic_count))?;
+
+        let diatonic_ratio = diatonic_count as f64 / pitch_classes.len() as f64;
+        (diatonic_ratio >= DETECT_KEY_MIN_DIATONIC_RATIO).then_some(key)
+    }
+
+    /// Finds each dominant 7th chord that resolves down a fifth to the chord
+    /// after it, like G7 to C, as `(dominant, resolution)` index pairs. Both
+    /// chords must have a quality [`Chord::identify_quality`] can name, and
+    /// the resolution can be of any quality.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordProgression, ChordQuality, Note, NoteModifier, C, D, G};
+    ///
+    /// let progression = ChordProgression::new(vec![
+    ///     ChordQuality::Minor7th.to_chord(Note::new(D, 3, NoteModifier::Natural)),
+    ///     ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural)),
+    ///     ChordQuality::Major7th.to_chord(Note::new(C, 3, NoteModifier::Natural)),
+    /// ]);
+    ///
+    /// assert_eq!(progression.dominant_motion_analysis(), vec![(1, 2)]);
+    /// ```
+    pub fn dominant_motion_analysis(&self) -> Vec<(usize, usize)> {
+        let identified: Vec<Option<(Note, ChordQuality)>> = self
+            .chords
+            .iter()
+            .map(|chord| chord.identify_quality())
+            .collect();
+
+        identified
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let (Some((dominant_root, quality)), Some((resolution_root, _))) =
+                    (pair[0], pair[1])
+                else {
+                    return None;
+                };
+
+                let root_motion = (resolution_root.to_semitones_from_c0()
+                    - dominant_root.to_semitones_from_c0())
+                .rem_euclid(12);
+
+                (quality == ChordQuality::Dominant7th
+                    && root_motion == SimpleInterval::PerfectFourth.semitones())
+                .then_some((i, i + 1))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{RawNote, Semitone, A, B, C, D, E, F, G};
+
+    /// Sums how far each voice moves between consecutive chords, pairing
+    /// voices by position.
+    fn total_movement(progression: &ChordProgression) -> Semitone {
+        progression
+            .chords()
+            .windows(2)
+            .map(|pair| {
+                let from = pair[0].sort_by_pitch();
+                let to = pair[1].sort_by_pitch();
+                from.notes()
+                    .iter()
+                    .zip(to.notes())
+                    .map(|(a, b)| (a.to_semitones_from_c0() - b.to_semitones_from_c0()).abs())
+                    .sum::<Semitone>()
+            })
+            .sum()
+    }
+
+    fn root_position(roots: &[(RawNote, ChordQuality)]) -> ChordProgression {
+        ChordProgression::new(
+            roots
+                .iter()
+                .map(|(root, quality)| quality.to_chord(Note::new(*root, 3, NoteModifier::Natural)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn voice_leading_moves_less_than_root_position() {
+        let progression = root_position(&[
+            (C, ChordQuality::Major),
+            (A, ChordQuality::Minor),
+            (D, ChordQuality::Minor),
+            (G, ChordQuality::Dominant7th),
+            (C, ChordQuality::Major),
+        ]);
+
+        let voiced = progression.voice_lead();
+
+        assert_eq!(voiced.chords().len(), progression.chords().len());
+        assert!(total_movement(&voiced) < total_movement(&progression));
+
+        for (voiced, original) in voiced.chords().iter().zip(progression.chords()) {
+            assert_eq!(voiced.pitch_classes(), original.pitch_classes());
+        }
+    }
+
+    #[test]
+    fn voice_leads_from_a_starting_voicing() {
+        let progression = root_position(&[(C, ChordQuality::Major), (F, ChordQuality::Major)]);
+        let start = Chord::new(vec![
+            Note::new(G, 3, NoteModifier::Natural),
+            Note::new(C, 4, NoteModifier::Natural),
+            Note::new(E, 4, NoteModifier::Natural),
+        ]);
+
+        let voiced = progression.voice_lead_from(start.clone());
+        assert_eq!(voiced.chords()[0], start);
+        assert_eq!(voiced.chords()[1].to_string(), "[A3, C4, F4]");
+
+        assert_eq!(
+            ChordProgression::default().voice_lead(),
+            ChordProgression::default()
+        );
+    }
+
+    #[test]
+    fn detects_keys() {
+        let ii_v_i = root_position(&[
+            (D, ChordQuality::Minor7th),
+            (G, ChordQuality::Dominant7th),
+            (C, ChordQuality::Major),
+        ]);
+        let key = ii_v_i.detect_key().unwrap();
+        assert_eq!(key.root(), AbstractNote::from(C));
+        assert_eq!(key.mode(), ScaleMode::Ionian);
+
+        // The same notes ending on A are heard in A minor.
+        let minor = root_position(&[
+            (D, ChordQuality::Minor),
+            (E, ChordQuality::Minor),
+            (A, ChordQuality::Minor),
+        ]);
+        let key = minor.detect_key().unwrap();
+        assert_eq!(key.root(), AbstractNote::from(A));
+        assert_eq!(key.mode(), ScaleMode::Aeolian);
+
+        // Db major has fewer flats than C# major has sharps.
+        let db_major = ChordProgression::new(vec![
+            ChordQuality::Major.to_chord(Note::new(D, 3, NoteModifier::Flat)),
+            ChordQuality::Major.to_chord(Note::new(G, 3, NoteModifier::Flat)),
+            ChordQuality::Major.to_chord(Note::new(A, 3, NoteModifier::Flat)),
+        ]);
+        assert_eq!(
+            db_major.detect_key().unwrap().root(),
+            AbstractNote::try_from("Db").unwrap()
+        );
+
+        let chromatic = ChordProgression::new(
+            (0..12)
+                .map(|semitones| {
+                    ChordQuality::Major.to_chord(Note::from_semitones_from_c0(
+                        48 + semitones,
+                        ModifierPreference::Sharp,
+                    ))
+                })
+                .collect(),
+        );
+        assert_eq!(chromatic.detect_key(), None);
+        assert_eq!(ChordProgression::default().detect_key(), None);
+    }
+
+    #[test]
+    fn finds_dominant_motion() {
+        let v_i = root_position(&[(G, ChordQuality::Dominant7th), (C, ChordQuality::Major)]);
+        assert_eq!(v_i.dominant_motion_analysis(), vec![(0, 1)]);
+
+        let ii_v_i = root_position(&[
+            (D, ChordQuality::Minor7th),
+            (G, ChordQuality::Dominant7th),
+            (C, ChordQuality::Major7th),
+        ]);
+        assert_eq!(ii_v_i.dominant_motion_analysis(), vec![(1, 2)]);
+
+        // The backdoor dominant, bVII7, resolves up a whole step rather than
+        // down a fifth, so it isn't dominant motion.
+        let backdoor = ChordProgression::new(vec![
+            ChordQuality::Minor7th.to_chord(Note::new(F, 3, NoteModifier::Natural)),
+            ChordQuality::Dominant7th.to_chord(Note::new(B, 3, NoteModifier::Flat)),
+            ChordQuality::Major7th.to_chord(Note::new(C, 4, NoteModifier::Natural)),
+        ]);
+        assert_eq!(backdoor.dominant_motion_analysis(), vec![]);
+
+        let chain_of_dominants = root_position(&[
+            (E, ChordQuality::Dominant7th),
+            (A, ChordQuality::Dominant7th),
+            (D, ChordQuality::Dominant7th),
+            (G, ChordQuality::Dominant7th),
+            (C, ChordQuality::Major),
+        ]);
+        assert_eq!(
+            chain_of_dominants.dominant_motion_analysis(),
+            vec![(0, 1), (1, 2), (2, 3), (3, 4)]
+        );
+
+        // A major triad on the fifth isn't a dominant 7th.
+        let plain_v_i = root_position(&[(G, ChordQuality::Major), (C, ChordQuality::Major)]);
+        assert_eq!(plain_v_i.dominant_motion_analysis(), vec![]);
+        assert_eq!(
+            ChordProgression::default().dominant_motion_analysis(),
+            vec![]
+        );
+    }
+}
@@ -1,7 +1,14 @@
+use std::fmt::Display;
+
+use strum::IntoEnumIterator;
+
 use super::Chord;
-use crate::{Note, SimpleInterval};
+use crate::{
+    AbstractNote, CompoundInterval, Interval, Note, NoteRangeError, Semitone, SimpleInterval,
+};
 
-#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Default, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChordQuality {
     #[default]
     Major,
@@ -18,6 +25,7 @@ pub enum ChordQuality {
     Minor11th,
     Minor13th,
     MinorMajor7thFlat13th,
+    Dominant7th,
     Augmented,
     Augmented7th,
     AugmentedMajor7th,
@@ -25,149 +33,471 @@ pub enum ChordQuality {
     Diminished7th,
     Suspended2nd,
     Suspended4th,
+    /// The Italian augmented sixth, a major third and augmented sixth above
+    /// the root. In C major it is built on Ab: Ab C F#.
+    Italian6,
+    /// The French augmented sixth, which adds an augmented fourth to the
+    /// Italian sixth. In C major it is built on Ab: Ab C D F#.
+    French6,
+    /// The German augmented sixth, which adds a perfect fifth to the Italian
+    /// sixth. In C major it is built on Ab: Ab C Eb F#.
+    German6,
+    /// Three notes stacked in perfect fourths, like C F Bb.
+    Quartal3,
+    /// Four notes stacked in perfect fourths, like C F Bb Eb. The top note is
+    /// a minor tenth above the root.
+    Quartal4,
+    /// The Neapolitan chord, a major triad on the lowered second degree. Unlike
+    /// the other qualities it is built from the tonic of the key rather than
+    /// its own root, so in C it is Db F Ab. It is traditionally played in
+    /// first inversion, with the third in the bass.
+    Neapolitan,
 }
 
 impl ChordQuality {
-    pub fn to_intervals(&self) -> Vec<SimpleInterval> {
-        todo!()
+    /// Gets the interval of each note in this chord quality above the note
+    /// it's built from, lowest first. Notes past the octave are compound
+    /// intervals, so a major 9th ends with a major ninth rather than a major
+    /// second. The intervals are named by how the chord is spelled, so an
+    /// augmented triad has an augmented fifth rather than a minor sixth.
+    ///
+    /// The Neapolitan is built from the tonic rather than its own root, so its
+    /// intervals start from a minor second.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Interval, SimpleInterval};
+    ///
+    /// assert_eq!(
+    ///     ChordQuality::Diminished7th.to_intervals(),
+    ///     vec![
+    ///         Interval::Simple(SimpleInterval::PerfectUnison),
+    ///         Interval::Simple(SimpleInterval::MinorThird),
+    ///         Interval::Simple(SimpleInterval::DiminishedFifth),
+    ///         Interval::Simple(SimpleInterval::DiminishedSeventh),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_intervals(&self) -> Vec<Interval> {
+        use CompoundInterval::*;
+        use SimpleInterval::*;
+
+        let simple = Interval::Simple;
+        let compound = Interval::Compound;
+
+        match self {
+            ChordQuality::Major => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+            ],
+            ChordQuality::Major6th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MajorSixth),
+            ],
+            ChordQuality::Major7th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+            ],
+            ChordQuality::Major9th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+                compound(MajorNinth),
+            ],
+            ChordQuality::Major11th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+                compound(MajorNinth),
+                compound(PerfectEleventh),
+            ],
+            ChordQuality::Major13th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+                compound(MajorNinth),
+                compound(PerfectEleventh),
+                compound(MajorThirteenth),
+            ],
+            ChordQuality::Minor => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+            ],
+            ChordQuality::Minor6th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MajorSixth),
+            ],
+            ChordQuality::Minor7th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MinorSeventh),
+            ],
+            ChordQuality::MinorMajor7th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+            ],
+            ChordQuality::Minor9th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MinorSeventh),
+                compound(MajorNinth),
+            ],
+            ChordQuality::Minor11th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MinorSeventh),
+                compound(MajorNinth),
+                compound(PerfectEleventh),
+            ],
+            ChordQuality::Minor13th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MinorSeventh),
+                compound(MajorNinth),
+                compound(PerfectEleventh),
+                compound(MajorThirteenth),
+            ],
+            ChordQuality::MinorMajor7thFlat13th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(PerfectFifth),
+                simple(MajorSeventh),
+                compound(MinorThirteenth),
+            ],
+            ChordQuality::Dominant7th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(MinorSeventh),
+            ],
+            ChordQuality::Augmented => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(AugmentedFifth),
+            ],
+            ChordQuality::Augmented7th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(AugmentedFifth),
+                simple(MinorSeventh),
+            ],
+            ChordQuality::AugmentedMajor7th => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(AugmentedFifth),
+                simple(MajorSeventh),
+            ],
+            ChordQuality::Diminished => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(DiminishedFifth),
+            ],
+            ChordQuality::Diminished7th => vec![
+                simple(PerfectUnison),
+                simple(MinorThird),
+                simple(DiminishedFifth),
+                simple(DiminishedSeventh),
+            ],
+            ChordQuality::Suspended2nd => vec![
+                simple(PerfectUnison),
+                simple(MajorSecond),
+                simple(PerfectFifth),
+            ],
+            ChordQuality::Suspended4th => vec![
+                simple(PerfectUnison),
+                simple(PerfectFourth),
+                simple(PerfectFifth),
+            ],
+            ChordQuality::Italian6 => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(AugmentedSixth),
+            ],
+            ChordQuality::French6 => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(AugmentedFourth),
+                simple(AugmentedSixth),
+            ],
+            ChordQuality::German6 => vec![
+                simple(PerfectUnison),
+                simple(MajorThird),
+                simple(PerfectFifth),
+                simple(AugmentedSixth),
+            ],
+            ChordQuality::Quartal3 => vec![
+                simple(PerfectUnison),
+                simple(PerfectFourth),
+                simple(MinorSeventh),
+            ],
+            ChordQuality::Quartal4 => vec![
+                simple(PerfectUnison),
+                simple(PerfectFourth),
+                simple(MinorSeventh),
+                compound(MinorTenth),
+            ],
+            ChordQuality::Neapolitan => vec![
+                simple(MinorSecond),
+                simple(PerfectFourth),
+                simple(MinorSixth),
+            ],
+        }
     }
 
-    pub fn to_notes(&self, root: Note) -> Vec<Note> {
+    /// Gets the semitones above the root of each note in this chord quality,
+    /// starting with the root itself. Extensions past the octave are kept as-is,
+    /// so a major 9th includes 14 rather than 2.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert_eq!(ChordQuality::Major.to_semitone_pattern(), vec![0, 4, 7]);
+    /// assert_eq!(ChordQuality::Minor9th.to_semitone_pattern(), vec![0, 3, 7, 10, 14]);
+    /// ```
+    pub fn to_semitone_pattern(&self) -> Vec<Semitone> {
         match self {
+            ChordQuality::Major => vec![0, 4, 7],
+            ChordQuality::Major6th => vec![0, 4, 7, 9],
+            ChordQuality::Major7th => vec![0, 4, 7, 11],
+            ChordQuality::Major9th => vec![0, 4, 7, 11, 14],
+            ChordQuality::Major11th => vec![0, 4, 7, 11, 14, 17],
+            ChordQuality::Major13th => vec![0, 4, 7, 11, 14, 17, 21],
+            ChordQuality::Minor => vec![0, 3, 7],
+            ChordQuality::Minor6th => vec![0, 3, 7, 9],
+            ChordQuality::Minor7th => vec![0, 3, 7, 10],
+            ChordQuality::MinorMajor7th => vec![0, 3, 7, 11],
+            ChordQuality::Minor9th => vec![0, 3, 7, 10, 14],
+            ChordQuality::Minor11th => vec![0, 3, 7, 10, 14, 17],
+            ChordQuality::Minor13th => vec![0, 3, 7, 10, 14, 17, 21],
+            ChordQuality::MinorMajor7thFlat13th => vec![0, 3, 7, 11, 20],
+            ChordQuality::Dominant7th => vec![0, 4, 7, 10],
+            ChordQuality::Augmented => vec![0, 4, 8],
+            ChordQuality::Augmented7th => vec![0, 4, 8, 10],
+            ChordQuality::AugmentedMajor7th => vec![0, 4, 8, 11],
+            ChordQuality::Diminished => vec![0, 3, 6],
+            ChordQuality::Diminished7th => vec![0, 3, 6, 9],
+            ChordQuality::Suspended2nd => vec![0, 2, 7],
+            ChordQuality::Suspended4th => vec![0, 5, 7],
+            ChordQuality::Italian6 => vec![0, 4, 10],
+            ChordQuality::French6 => vec![0, 4, 6, 10],
+            ChordQuality::German6 => vec![0, 4, 7, 10],
+            ChordQuality::Quartal3 => vec![0, 5, 10],
+            ChordQuality::Quartal4 => vec![0, 5, 10, 15],
+            ChordQuality::Neapolitan => vec![1, 5, 8],
+        }
+    }
+
+    /// Gets the notes of this chord quality built up from `root`. Panics if
+    /// any note would be outside the range of [`crate::Octave`], see
+    /// [`ChordQuality::try_to_notes`].
+    pub fn to_notes(&self, root: Note) -> Vec<Note> {
+        match self.try_to_notes(root) {
+            Ok(notes) => notes,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Gets the notes of this chord quality built up from `root`, or an error
+    /// if any of them would be outside the range of [`crate::Octave`].
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, Note, NoteModifier, NoteRangeError, A};
+    ///
+    /// let a9 = Note::new(A, 9, NoteModifier::Natural);
+    /// assert!(matches!(ChordQuality::Major.try_to_notes(a9), Err(NoteRangeError::TooHigh(_))));
+    /// ```
+    pub fn try_to_notes(&self, root: Note) -> Result<Vec<Note>, NoteRangeError> {
+        let notes = match self {
             ChordQuality::Major => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
                 vec![root, third, fifth]
             }
             ChordQuality::Major6th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
-                let sixth = root.add_semitones(9);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let sixth = root.try_add_semitones(9)?;
                 vec![root, third, fifth, sixth]
             }
             ChordQuality::Major7th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::Major9th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
-                let ninth = root.add_semitones(14);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
+                let ninth = root.try_add_semitones(14)?;
                 vec![root, third, fifth, seventh, ninth]
             }
             ChordQuality::Major11th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
-                let ninth = root.add_semitones(14);
-                let eleventh = root.add_semitones(17);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
+                let ninth = root.try_add_semitones(14)?;
+                let eleventh = root.try_add_semitones(17)?;
                 vec![root, third, fifth, seventh, ninth, eleventh]
             }
             ChordQuality::Major13th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
-                let ninth = root.add_semitones(14);
-                let eleventh = root.add_semitones(17);
-                let thirteenth = root.add_semitones(21);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
+                let ninth = root.try_add_semitones(14)?;
+                let eleventh = root.try_add_semitones(17)?;
+                let thirteenth = root.try_add_semitones(21)?;
                 vec![root, third, fifth, seventh, ninth, eleventh, thirteenth]
             }
             ChordQuality::Minor => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
                 vec![root, third, fifth]
             }
             ChordQuality::Minor6th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let sixth = root.add_semitones(9);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let sixth = root.try_add_semitones(9)?;
                 vec![root, third, fifth, sixth]
             }
             ChordQuality::Minor7th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(10);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(10)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::MinorMajor7th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::Minor9th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(10);
-                let ninth = root.add_semitones(14);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(10)?;
+                let ninth = root.try_add_semitones(14)?;
                 vec![root, third, fifth, seventh, ninth]
             }
             ChordQuality::Minor11th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(10);
-                let ninth = root.add_semitones(14);
-                let eleventh = root.add_semitones(17);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(10)?;
+                let ninth = root.try_add_semitones(14)?;
+                let eleventh = root.try_add_semitones(17)?;
                 vec![root, third, fifth, seventh, ninth, eleventh]
             }
             ChordQuality::Minor13th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(10);
-                let ninth = root.add_semitones(14);
-                let eleventh = root.add_semitones(17);
-                let thirteenth = root.add_semitones(21);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(10)?;
+                let ninth = root.try_add_semitones(14)?;
+                let eleventh = root.try_add_semitones(17)?;
+                let thirteenth = root.try_add_semitones(21)?;
                 vec![root, third, fifth, seventh, ninth, eleventh, thirteenth]
             }
             ChordQuality::MinorMajor7thFlat13th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(7);
-                let seventh = root.add_semitones(11);
-                let thirteenth = root.add_semitones(20);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(11)?;
+                let thirteenth = root.try_add_semitones(20)?;
                 vec![root, third, fifth, seventh, thirteenth]
             }
+            ChordQuality::Dominant7th => {
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(7)?;
+                let seventh = root.try_add_semitones(10)?;
+                vec![root, third, fifth, seventh]
+            }
             ChordQuality::Augmented => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(8);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(8)?;
                 vec![root, third, fifth]
             }
             ChordQuality::Augmented7th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(8);
-                let seventh = root.add_semitones(10);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(8)?;
+                let seventh = root.try_add_semitones(10)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::AugmentedMajor7th => {
-                let third = root.add_semitones(4);
-                let fifth = root.add_semitones(8);
-                let seventh = root.add_semitones(11);
+                let third = root.try_add_semitones(4)?;
+                let fifth = root.try_add_semitones(8)?;
+                let seventh = root.try_add_semitones(11)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::Diminished => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(6);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(6)?;
                 vec![root, third, fifth]
             }
             ChordQuality::Diminished7th => {
-                let third = root.add_semitones(3);
-                let fifth = root.add_semitones(6);
-                let seventh = root.add_semitones(9);
+                let third = root.try_add_semitones(3)?;
+                let fifth = root.try_add_semitones(6)?;
+                let seventh = root.try_add_semitones(9)?;
                 vec![root, third, fifth, seventh]
             }
             ChordQuality::Suspended2nd => {
-                let second = root.add_semitones(2);
-                let fifth = root.add_semitones(7);
+                let second = root.try_add_semitones(2)?;
+                let fifth = root.try_add_semitones(7)?;
                 vec![root, second, fifth]
             }
             ChordQuality::Suspended4th => {
-                let fourth = root.add_semitones(5);
-                let fifth = root.add_semitones(7);
+                let fourth = root.try_add_semitones(5)?;
+                let fifth = root.try_add_semitones(7)?;
                 vec![root, fourth, fifth]
             }
-        }
+            // Augmented sixth chords are named for their spelling, so these
+            // are spelled by interval rather than by semitones. On Ab, the
+            // sixth is F# rather than Gb.
+            ChordQuality::Italian6 => {
+                let third = spelled_above(root, SimpleInterval::MajorThird)?;
+                let sixth = spelled_above(root, SimpleInterval::AugmentedSixth)?;
+                vec![root, third, sixth]
+            }
+            ChordQuality::French6 => {
+                let third = spelled_above(root, SimpleInterval::MajorThird)?;
+                let fourth = spelled_above(root, SimpleInterval::AugmentedFourth)?;
+                let sixth = spelled_above(root, SimpleInterval::AugmentedSixth)?;
+                vec![root, third, fourth, sixth]
+            }
+            ChordQuality::German6 => {
+                let third = spelled_above(root, SimpleInterval::MajorThird)?;
+                let fifth = spelled_above(root, SimpleInterval::PerfectFifth)?;
+                let sixth = spelled_above(root, SimpleInterval::AugmentedSixth)?;
+                vec![root, third, fifth, sixth]
+            }
+            // Each note is a perfect fourth above the last, so on C the third
+            // note is Bb rather than A#.
+            ChordQuality::Quartal3 => stack_fourths(root, 3)?,
+            ChordQuality::Quartal4 => stack_fourths(root, 4)?,
+            // The root is spelled as a second above the tonic, so in C it is
+            // Db rather than C#.
+            ChordQuality::Neapolitan => {
+                let flat_second = spelled_above(root, SimpleInterval::MinorSecond)?;
+                let third = spelled_above(flat_second, SimpleInterval::MajorThird)?;
+                let fifth = spelled_above(flat_second, SimpleInterval::PerfectFifth)?;
+                vec![flat_second, third, fifth]
+            }
+        };
+
+        Ok(notes)
     }
 
     pub fn to_chord(&self, root: Note) -> Chord {
@@ -190,6 +520,7 @@ impl ChordQuality {
             ChordQuality::Minor11th => "m11",
             ChordQuality::Minor13th => "m13",
             ChordQuality::MinorMajor7thFlat13th => "mM7b13",
+            ChordQuality::Dominant7th => "7",
             ChordQuality::Augmented => "aug",
             ChordQuality::Augmented7th => "aug7",
             ChordQuality::AugmentedMajor7th => "augM7",
@@ -197,6 +528,82 @@ impl ChordQuality {
             ChordQuality::Diminished7th => "dim7",
             ChordQuality::Suspended2nd => "sus2",
             ChordQuality::Suspended4th => "sus4",
+            ChordQuality::Italian6 => "It6",
+            ChordQuality::French6 => "Fr6",
+            ChordQuality::German6 => "Ger6",
+            ChordQuality::Quartal3 => "q4-3",
+            ChordQuality::Quartal4 => "q4-4",
+            ChordQuality::Neapolitan => "bII",
+        }
+    }
+
+    /// Parses a chord quality from any of the common ways of writing it, like
+    /// "maj7", "M7" or "Δ7" for a major seventh. Letters are compared without
+    /// case, except that an upper case "M" means major and a lower case "m"
+    /// means minor. An empty string is a major chord, as a chord symbol with
+    /// just a root would be. Use [`ChordQuality::try_from`] to accept only
+    /// the [`ChordQuality::short_name`] of each quality.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert_eq!(ChordQuality::from_str_lenient("M7"), Some(ChordQuality::Major7th));
+    /// assert_eq!(ChordQuality::from_str_lenient("min7"), Some(ChordQuality::Minor7th));
+    /// assert_eq!(ChordQuality::from_str_lenient("°7"), Some(ChordQuality::Diminished7th));
+    /// assert_eq!(ChordQuality::from_str_lenient("wat"), None);
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Option<ChordQuality> {
+        let s = s.trim();
+
+        if let Ok(quality) = ChordQuality::try_from(s) {
+            return Some(quality);
+        }
+
+        // Aliases where the case of the "M" matters, which can't be lowered.
+        let case_sensitive = match s {
+            "M" => Some(ChordQuality::Major),
+            "M6" => Some(ChordQuality::Major6th),
+            "M7" => Some(ChordQuality::Major7th),
+            "M9" => Some(ChordQuality::Major9th),
+            "M11" => Some(ChordQuality::Major11th),
+            "M13" => Some(ChordQuality::Major13th),
+            "+M7" => Some(ChordQuality::AugmentedMajor7th),
+            _ => None,
+        };
+        if case_sensitive.is_some() {
+            return case_sensitive;
+        }
+
+        match s.to_lowercase().as_str() {
+            "" | "maj" | "major" | "δ" => Some(ChordQuality::Major),
+            "6" | "maj6" | "major6" | "add6" => Some(ChordQuality::Major6th),
+            "maj7" | "major7" | "ma7" | "δ7" => Some(ChordQuality::Major7th),
+            "maj9" | "major9" | "δ9" => Some(ChordQuality::Major9th),
+            "maj11" | "major11" | "δ11" => Some(ChordQuality::Major11th),
+            "maj13" | "major13" | "δ13" => Some(ChordQuality::Major13th),
+            "m" | "min" | "minor" | "-" => Some(ChordQuality::Minor),
+            "m6" | "min6" | "minor6" | "-6" => Some(ChordQuality::Minor6th),
+            "m7" | "min7" | "minor7" | "-7" => Some(ChordQuality::Minor7th),
+            "mmaj7" | "minmaj7" | "m(maj7)" | "-maj7" | "-δ7" => Some(ChordQuality::MinorMajor7th),
+            "m9" | "min9" | "minor9" | "-9" => Some(ChordQuality::Minor9th),
+            "m11" | "min11" | "minor11" | "-11" => Some(ChordQuality::Minor11th),
+            "m13" | "min13" | "minor13" | "-13" => Some(ChordQuality::Minor13th),
+            "mmaj7b13" | "minmaj7b13" | "-maj7b13" => Some(ChordQuality::MinorMajor7thFlat13th),
+            "7" | "dom" | "dom7" => Some(ChordQuality::Dominant7th),
+            "aug" | "augmented" | "+" | "#5" => Some(ChordQuality::Augmented),
+            "aug7" | "+7" | "7#5" | "7+" => Some(ChordQuality::Augmented7th),
+            "augmaj7" | "+maj7" | "maj7#5" | "δ7#5" => Some(ChordQuality::AugmentedMajor7th),
+            "dim" | "diminished" | "°" | "o" => Some(ChordQuality::Diminished),
+            "dim7" | "°7" | "o7" => Some(ChordQuality::Diminished7th),
+            "sus2" => Some(ChordQuality::Suspended2nd),
+            "sus" | "sus4" => Some(ChordQuality::Suspended4th),
+            "it6" | "it+6" => Some(ChordQuality::Italian6),
+            "fr6" | "fr+6" => Some(ChordQuality::French6),
+            "ger6" | "ger+6" | "gr6" => Some(ChordQuality::German6),
+            "q4-3" | "quartal" | "quartal3" => Some(ChordQuality::Quartal3),
+            "q4-4" | "quartal4" => Some(ChordQuality::Quartal4),
+            "bii" | "n" | "n6" | "neapolitan" => Some(ChordQuality::Neapolitan),
+            _ => None,
         }
     }
 
@@ -216,6 +623,7 @@ impl ChordQuality {
             ChordQuality::Minor11th => "Minor 11th",
             ChordQuality::Minor13th => "Minor 13th",
             ChordQuality::MinorMajor7thFlat13th => "Minor Major 7th Flat 13th",
+            ChordQuality::Dominant7th => "Dominant 7th",
             ChordQuality::Augmented => "Augmented",
             ChordQuality::Augmented7th => "Augmented 7th",
             ChordQuality::AugmentedMajor7th => "Augmented Major 7th",
@@ -223,6 +631,445 @@ impl ChordQuality {
             ChordQuality::Diminished7th => "Diminished 7th",
             ChordQuality::Suspended2nd => "Suspended 2nd",
             ChordQuality::Suspended4th => "Suspended 4th",
+            ChordQuality::Italian6 => "Italian Augmented 6th",
+            ChordQuality::French6 => "French Augmented 6th",
+            ChordQuality::German6 => "German Augmented 6th",
+            ChordQuality::Quartal3 => "Quartal Triad",
+            ChordQuality::Quartal4 => "Quartal Tetrad",
+            ChordQuality::Neapolitan => "Neapolitan",
+        }
+    }
+
+    /// Whether this quality is in the major family, built on a major triad
+    /// with a major seventh if it has a seventh at all.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert!(ChordQuality::Major9th.is_category_major());
+    /// assert!(!ChordQuality::Dominant7th.is_category_major());
+    /// ```
+    pub fn is_category_major(&self) -> bool {
+        matches!(
+            self,
+            ChordQuality::Major
+                | ChordQuality::Major6th
+                | ChordQuality::Major7th
+                | ChordQuality::Major9th
+                | ChordQuality::Major11th
+                | ChordQuality::Major13th
+                | ChordQuality::AugmentedMajor7th
+                | ChordQuality::Neapolitan
+        )
+    }
+
+    /// Whether this quality is in the minor family, built on a minor triad.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert!(ChordQuality::MinorMajor7th.is_category_minor());
+    /// assert!(!ChordQuality::Diminished.is_category_minor());
+    /// ```
+    pub fn is_category_minor(&self) -> bool {
+        matches!(
+            self,
+            ChordQuality::Minor
+                | ChordQuality::Minor6th
+                | ChordQuality::Minor7th
+                | ChordQuality::MinorMajor7th
+                | ChordQuality::Minor9th
+                | ChordQuality::Minor11th
+                | ChordQuality::Minor13th
+                | ChordQuality::MinorMajor7thFlat13th
+        )
+    }
+
+    /// Whether this quality is a dominant chord, with a major third and a
+    /// minor seventh.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert!(ChordQuality::Augmented7th.is_dominant());
+    /// assert!(!ChordQuality::Major7th.is_dominant());
+    /// ```
+    pub fn is_dominant(&self) -> bool {
+        matches!(self, ChordQuality::Dominant7th | ChordQuality::Augmented7th)
+    }
+
+    /// Whether this quality is built on a diminished triad.
+    ///
+    /// ```rust
+    /// use note_lib::ChordQuality;
+    ///
+    /// assert!(ChordQuality::Diminished7th.is_diminished());
+    /// assert!(!ChordQuality::Minor.is_diminished());
+    /// ```
+    pub fn is_diminished(&self) -> bool {
+        matches!(self, ChordQuality::Diminished | ChordQuality::Diminished7th)
+    }
+}
+
+/// Displays the quality's [`ChordQuality::short_name`], like `m7`. The
+/// alternate form (`{:#}`) displays its [`ChordQuality::long_name`] instead,
+/// like `Minor 7th`.
+impl Display for ChordQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.long_name())
+        } else {
+            write!(f, "{}", self.short_name())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChordQualityParseError {
+    EmptyInput,
+    UnknownQuality,
+}
+
+/// Parses a chord quality from its [`ChordQuality::short_name`] only. See
+/// [`ChordQuality::from_str_lenient`] for parsing other spellings.
+impl TryFrom<&str> for ChordQuality {
+    type Error = ChordQualityParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(ChordQualityParseError::EmptyInput);
+        }
+
+        ChordQuality::iter()
+            .find(|quality| quality.short_name() == value)
+            .ok_or(ChordQualityParseError::UnknownQuality)
+    }
+}
+
+/// Places the note `interval` above `root`, spelled by the interval.
+fn spelled_above(root: Note, interval: SimpleInterval) -> Result<Note, NoteRangeError> {
+    let placed = root.try_add_interval(interval)?;
+
+    Ok(Note::place_at_semitones(
+        AbstractNote::from(root).spelled_add_interval(interval),
+        placed.to_semitones_from_c0(),
+    ))
+}
+
+/// Stacks `count` notes a perfect fourth apart, starting from `root`.
+fn stack_fourths(root: Note, count: usize) -> Result<Vec<Note>, NoteRangeError> {
+    let mut notes = vec![root];
+    while notes.len() < count {
+        let next = spelled_above(*notes.last().unwrap(), SimpleInterval::PerfectFourth)?;
+        notes.push(next);
+    }
+
+    Ok(notes)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{NoteModifier, A, C, D, E, F};
+
+    use super::*;
+
+    #[test]
+    fn semitone_patterns_match_notes() {
+        let root = Note::new(C, 4, NoteModifier::Natural);
+
+        for quality in ChordQuality::iter() {
+            let semitones: Vec<Semitone> = quality
+                .to_notes(root)
+                .iter()
+                .map(|note| note.to_semitones_from_c0() - root.to_semitones_from_c0())
+                .collect();
+
+            assert_eq!(semitones, quality.to_semitone_pattern(), "{:?}", quality);
+        }
+    }
+
+    #[test]
+    fn augmented_sixths_are_spelled() {
+        let names = |quality: ChordQuality| {
+            quality
+                .to_notes(Note::new(A, 3, NoteModifier::Flat))
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(ChordQuality::Italian6), vec!["Ab3", "C4", "F#4"]);
+        assert_eq!(names(ChordQuality::French6), vec!["Ab3", "C4", "D4", "F#4"]);
+        assert_eq!(
+            names(ChordQuality::German6),
+            vec!["Ab3", "C4", "Eb4", "F#4"]
+        );
+    }
+
+    #[test]
+    fn parses_short_names_strictly() {
+        for quality in ChordQuality::iter() {
+            assert_eq!(ChordQuality::try_from(quality.short_name()), Ok(quality));
+        }
+
+        assert_eq!(
+            ChordQuality::try_from(""),
+            Err(ChordQualityParseError::EmptyInput)
+        );
+        assert_eq!(
+            ChordQuality::try_from("min7"),
+            Err(ChordQualityParseError::UnknownQuality)
+        );
+        assert_eq!(
+            ChordQuality::try_from("MAJ"),
+            Err(ChordQualityParseError::UnknownQuality)
+        );
+    }
+
+    #[test]
+    fn parses_aliases_leniently() {
+        use ChordQuality::*;
+
+        let aliases = [
+            ("", Major),
+            ("M", Major),
+            ("maj", Major),
+            ("Major", Major),
+            ("Δ", Major),
+            ("6", Major6th),
+            ("M6", Major6th),
+            ("add6", Major6th),
+            ("M7", Major7th),
+            ("maj7", Major7th),
+            ("Ma7", Major7th),
+            ("Δ7", Major7th),
+            ("M9", Major9th),
+            ("maj9", Major9th),
+            ("Δ9", Major9th),
+            ("maj11", Major11th),
+            ("M13", Major13th),
+            ("m", Minor),
+            ("min", Minor),
+            ("minor", Minor),
+            ("-", Minor),
+            ("m6", Minor6th),
+            ("-6", Minor6th),
+            ("m7", Minor7th),
+            ("min7", Minor7th),
+            ("-7", Minor7th),
+            ("mM7", MinorMajor7th),
+            ("mMaj7", MinorMajor7th),
+            ("m(maj7)", MinorMajor7th),
+            ("-Δ7", MinorMajor7th),
+            ("m9", Minor9th),
+            ("min11", Minor11th),
+            ("-13", Minor13th),
+            ("mM7b13", MinorMajor7thFlat13th),
+            ("minmaj7b13", MinorMajor7thFlat13th),
+            ("7", Dominant7th),
+            ("dom", Dominant7th),
+            ("dom7", Dominant7th),
+            ("aug", Augmented),
+            ("+", Augmented),
+            ("aug7", Augmented7th),
+            ("+7", Augmented7th),
+            ("7#5", Augmented7th),
+            ("augM7", AugmentedMajor7th),
+            ("+M7", AugmentedMajor7th),
+            ("maj7#5", AugmentedMajor7th),
+            ("dim", Diminished),
+            ("°", Diminished),
+            ("o", Diminished),
+            ("dim7", Diminished7th),
+            ("°7", Diminished7th),
+            ("sus2", Suspended2nd),
+            ("sus", Suspended4th),
+            ("SUS4", Suspended4th),
+            ("It6", Italian6),
+            ("Fr+6", French6),
+            ("Ger6", German6),
+            ("Gr6", German6),
+            ("bII", Neapolitan),
+            ("N6", Neapolitan),
+        ];
+
+        for (alias, quality) in aliases {
+            assert_eq!(
+                ChordQuality::from_str_lenient(alias),
+                Some(quality),
+                "{:?}",
+                alias
+            );
+        }
+
+        // There is no half diminished quality yet.
+        assert_eq!(ChordQuality::from_str_lenient("ø7"), None);
+        assert_eq!(ChordQuality::from_str_lenient("maj8"), None);
+    }
+
+    #[test]
+    fn displays_short_and_long_names() {
+        assert_eq!(format!("{}", ChordQuality::Major), "maj");
+        assert_eq!(format!("{:#}", ChordQuality::Minor7th), "Minor 7th");
+
+        for quality in ChordQuality::iter() {
+            assert_eq!(quality.to_string(), quality.short_name());
+            assert_eq!(format!("{:#}", quality), quality.long_name());
+        }
+    }
+
+    #[test]
+    fn categorizes_qualities() {
+        for quality in ChordQuality::iter() {
+            let categories = [
+                quality.is_category_major(),
+                quality.is_category_minor(),
+                quality.is_dominant(),
+                quality.is_diminished(),
+            ];
+            let category_count = categories
+                .iter()
+                .filter(|in_category| **in_category)
+                .count();
+
+            // Augmented, suspended and quartal chords and the augmented
+            // sixths don't belong to any of the families.
+            let uncategorized = matches!(
+                quality,
+                ChordQuality::Augmented
+                    | ChordQuality::Suspended2nd
+                    | ChordQuality::Suspended4th
+                    | ChordQuality::Italian6
+                    | ChordQuality::French6
+                    | ChordQuality::German6
+                    | ChordQuality::Quartal3
+                    | ChordQuality::Quartal4
+            );
+
+            assert_eq!(
+                category_count,
+                if uncategorized { 0 } else { 1 },
+                "{:?}",
+                quality
+            );
+        }
+    }
+
+    #[test]
+    fn stacks_quartal_chords() {
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let names = |quality: ChordQuality| {
+            quality
+                .to_notes(c4)
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(ChordQuality::Quartal3), vec!["C4", "F4", "Bb4"]);
+        assert_eq!(
+            names(ChordQuality::Quartal4),
+            vec!["C4", "F4", "Bb4", "Eb5"]
+        );
+
+        for quality in [ChordQuality::Quartal3, ChordQuality::Quartal4] {
+            assert_eq!(quality.to_chord(c4).identify_quality(), Some((c4, quality)));
+        }
+    }
+
+    #[test]
+    fn builds_neapolitan_on_the_flat_second() {
+        let names = |tonic: Note| {
+            ChordQuality::Neapolitan
+                .to_notes(tonic)
+                .iter()
+                .map(|note| note.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(Note::new(C, 4, NoteModifier::Natural)),
+            vec!["Db4", "F4", "Ab4"]
+        );
+        assert_eq!(
+            names(Note::new(A, 3, NoteModifier::Natural)),
+            vec!["Bb3", "D4", "F4"]
+        );
+        assert_eq!(
+            names(Note::new(F, 4, NoteModifier::Sharp)),
+            vec!["G4", "B4", "D5"]
+        );
+        assert_eq!(
+            names(Note::new(E, 4, NoteModifier::Flat)),
+            vec!["Fb4", "Ab4", "Cb5"]
+        );
+
+        // The chord itself is a major triad on its own root.
+        let c4 = Note::new(C, 4, NoteModifier::Natural);
+        let db4 = Note::new(D, 4, NoteModifier::Flat);
+        assert_eq!(
+            ChordQuality::Neapolitan.to_chord(c4).identify_quality(),
+            Some((db4, ChordQuality::Major))
+        );
+    }
+
+    #[test]
+    fn intervals_match_notes() {
+        let root = Note::new(C, 4, NoteModifier::Natural);
+        let interval_semitones = |interval: &Interval| match interval {
+            Interval::Simple(simple) => simple.semitones(),
+            Interval::Compound(compound) => compound.semitones(),
+        };
+
+        for quality in ChordQuality::iter() {
+            let intervals = quality.to_intervals();
+
+            let semitones: Vec<Semitone> = intervals.iter().map(interval_semitones).collect();
+            assert_eq!(semitones, quality.to_semitone_pattern(), "{:?}", quality);
+
+            let pitch_classes: Vec<u8> = intervals
+                .iter()
+                .map(|interval| {
+                    root.add_semitones_unchecked(interval_semitones(interval))
+                        .to_semitones_from_c0()
+                        .rem_euclid(12) as u8
+                })
+                .collect();
+            let note_pitch_classes: Vec<u8> = quality
+                .to_notes(root)
+                .iter()
+                .map(|note| AbstractNote::from(*note).chromatic_index())
+                .collect();
+            assert_eq!(pitch_classes, note_pitch_classes, "{:?}", quality);
+        }
+
+        // Qualities spelled by interval match the letters of their notes too.
+        let ab3 = Note::new(A, 3, NoteModifier::Flat);
+        for quality in [
+            ChordQuality::Italian6,
+            ChordQuality::French6,
+            ChordQuality::German6,
+            ChordQuality::Quartal3,
+        ] {
+            let spelled: Vec<AbstractNote> = quality
+                .to_intervals()
+                .iter()
+                .map(|interval| match interval {
+                    Interval::Simple(simple) => {
+                        AbstractNote::from(ab3).spelled_add_interval(*simple)
+                    }
+                    Interval::Compound(compound) => {
+                        AbstractNote::from(ab3).spelled_add_interval(compound.get_simple_interval())
+                    }
+                })
+                .collect();
+            let notes: Vec<AbstractNote> = quality
+                .to_notes(ab3)
+                .iter()
+                .map(|note| AbstractNote::from(*note))
+                .collect();
+            assert_eq!(spelled, notes, "{:?}", quality);
         }
     }
 }
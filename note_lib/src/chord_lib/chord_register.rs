@@ -0,0 +1,57 @@
+use crate::{Note, NoteModifier, RawNote, Semitone};
+
+/// A rough band of pitches that a voice sits in, named after the vocal
+/// ranges. Real voices overlap a lot, so each register here is just the
+/// part of the range where that voice is most at home, and the registers
+/// don't overlap. See [`crate::Chord::register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChordRegister {
+    /// Below C2.
+    SubBass,
+    /// C2 up to B2.
+    Bass,
+    /// C3 up to G3.
+    Baritone,
+    /// G#3 up to D#4.
+    Tenor,
+    /// E4 up to B4.
+    Alto,
+    /// C5 and above.
+    Soprano,
+}
+
+impl ChordRegister {
+    /// Gets the register that `note` falls in.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordRegister, Note, NoteModifier, A, C};
+    ///
+    /// assert_eq!(ChordRegister::of_note(Note::new(C, 1, NoteModifier::Natural)), ChordRegister::SubBass);
+    /// assert_eq!(ChordRegister::of_note(Note::new(A, 4, NoteModifier::Natural)), ChordRegister::Alto);
+    /// ```
+    pub fn of_note(note: Note) -> ChordRegister {
+        ChordRegister::from_semitones_from_c0(note.to_semitones_from_c0())
+    }
+
+    /// Gets the register of the pitch `semitones` above C0.
+    pub fn from_semitones_from_c0(semitones: Semitone) -> ChordRegister {
+        let starts_at = |raw_note, octave, modifier| {
+            Note::new(raw_note, octave, modifier).to_semitones_from_c0()
+        };
+
+        if semitones < starts_at(RawNote::C, 2, NoteModifier::Natural) {
+            ChordRegister::SubBass
+        } else if semitones < starts_at(RawNote::C, 3, NoteModifier::Natural) {
+            ChordRegister::Bass
+        } else if semitones < starts_at(RawNote::G, 3, NoteModifier::Sharp) {
+            ChordRegister::Baritone
+        } else if semitones < starts_at(RawNote::E, 4, NoteModifier::Natural) {
+            ChordRegister::Tenor
+        } else if semitones < starts_at(RawNote::C, 5, NoteModifier::Natural) {
+            ChordRegister::Alto
+        } else {
+            ChordRegister::Soprano
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::Chord;
+
+/// A MIDI note on or note off message, placed at a time in ticks.
+///
+/// Note off messages are sent as real note off messages (`is_note_on` is
+/// `false`) with a release velocity of 0, rather than as note on messages with
+/// a velocity of 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MidiEvent {
+    pub time_ticks: u64,
+    pub note: u8,
+    pub velocity: u8,
+    pub is_note_on: bool,
+    pub channel: u8,
+}
+
+impl Chord {
+    /// Gets the MIDI events that play every note of this chord together on
+    /// channel 0, starting at `start_time` and lasting `duration_ticks`. All of
+    /// the note on events come first, followed by the note off events.
+    ///
+    /// `velocity` is clamped to 1 through 127, since a note on with a velocity
+    /// of 0 is read as a note off. Notes outside the MIDI range are skipped.
+    ///
+    /// ```rust
+    /// use note_lib::{Chord, Note, NoteModifier, C, E, G};
+    ///
+    /// let chord = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    /// let events = chord.to_midi_events(0, 480, 100);
+    ///
+    /// assert_eq!(events.len(), 6);
+    /// assert_eq!(events[0].note, 60);
+    /// assert_eq!(events[5].time_ticks, 480);
+    /// ```
+    pub fn to_midi_events(
+        &self,
+        start_time: u64,
+        duration_ticks: u64,
+        velocity: u8,
+    ) -> Vec<MidiEvent> {
+        let midi_numbers: Vec<u8> = self
+            .notes()
+            .iter()
+            .filter_map(|note| note.midi_number())
+            .collect();
+        let velocity = velocity.clamp(1, 127);
+
+        let note_ons = midi_numbers.iter().map(|note| MidiEvent {
+            time_ticks: start_time,
+            note: *note,
+            velocity,
+            is_note_on: true,
+            channel: 0,
+        });
+        let note_offs = midi_numbers.iter().map(|note| MidiEvent {
+            time_ticks: start_time + duration_ticks,
+            note: *note,
+            velocity: 0,
+            is_note_on: false,
+            channel: 0,
+        });
+
+        note_ons.chain(note_offs).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Note, NoteModifier, C, E, G};
+
+    use super::*;
+
+    #[test]
+    fn creates_note_on_and_off_events() {
+        let chord = Note::new(C, 4, NoteModifier::Natural)
+            + Note::new(E, 4, NoteModifier::Flat)
+            + Note::new(G, 4, NoteModifier::Natural)
+            + Note::new(C, 5, NoteModifier::Natural);
+
+        let events = chord.to_midi_events(960, 240, 90);
+        assert_eq!(events.len(), 2 * chord.notes().len());
+
+        let (note_ons, note_offs) = events.split_at(chord.notes().len());
+        for (event, expected_note) in note_ons.iter().zip([60, 63, 67, 72]) {
+            assert_eq!(event.note, expected_note);
+            assert_eq!(event.time_ticks, 960);
+            assert_eq!(event.velocity, 90);
+            assert!(event.is_note_on);
+        }
+        for (event, expected_note) in note_offs.iter().zip([60, 63, 67, 72]) {
+            assert_eq!(event.note, expected_note);
+            assert_eq!(event.time_ticks, 1200);
+            assert_eq!(event.velocity, 0);
+            assert!(!event.is_note_on);
+        }
+    }
+
+    #[test]
+    fn note_on_velocity_is_never_zero() {
+        let chord = Chord::new(vec![Note::new(C, 4, NoteModifier::Natural)]);
+        let events = chord.to_midi_events(0, 1, 0);
+
+        assert_eq!(events[0].velocity, 1);
+    }
+}
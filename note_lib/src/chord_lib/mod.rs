@@ -1,7 +1,17 @@
+mod arpeggio_direction;
 mod chord;
 mod chord_builder;
+mod chord_progression;
 mod chord_quality;
+mod chord_register;
+#[cfg(feature = "midi")]
+mod midi_event;
 
+pub use arpeggio_direction::*;
 pub use chord::*;
 pub use chord_builder::*;
+pub use chord_progression::*;
 pub use chord_quality::*;
+pub use chord_register::*;
+#[cfg(feature = "midi")]
+pub use midi_event::*;
@@ -12,9 +12,11 @@
 //! 0 semitones, but it is arithmetically possible.
 use std::fmt::Display;
 
+use strum::IntoEnumIterator;
+
 use crate::{
-    bias_simple_interval_quality, IntervalQuality, Semitone, SimpleInterval,
-    SimpleIntervalFromSemitones,
+    bias_simple_interval_quality, Cents, IntervalQuality, Octave, Semitone, SimpleInterval,
+    SimpleIntervalFromSemitones, SimpleIntervalNumber,
 };
 
 /// Represents an unusual combination of simple intervals.
@@ -121,7 +123,7 @@ impl From<SimpleIntervalFromSemitones> for OtherCompoundInterval {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, strum_macros::EnumIter)]
 pub enum CompoundInterval {
     DiminishedNinth,
 
@@ -167,6 +169,11 @@ pub enum CompoundInterval {
 }
 
 impl CompoundInterval {
+    /// The largest diatonic number that can be parsed, a 77th. That is the
+    /// widest interval between two notes in the range of [`Octave`], from
+    /// C-1 up to B9.
+    pub const MAX_NUMBER: i32 = (Octave::MAX - Octave::MIN) * 7 + 7;
+
     pub fn from_semitones(semitones: Semitone) -> CompoundInterval {
         match semitones {
             13 => CompoundInterval::MinorNinth,
@@ -221,6 +228,11 @@ impl CompoundInterval {
         }
     }
 
+    /// Get the size of this interval in cents, in equal temperament.
+    pub fn cents(&self) -> Cents {
+        Cents::from_semitones(self.semitones() as f64)
+    }
+
     /// Given a compound interval, return the top-most simple interval.
     /// For example, a compound interval of a 9th would return a simple interval
     /// of a 2nd.
@@ -327,3 +339,197 @@ impl Display for CompoundInterval {
         write!(f, "{}", name)
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntervalError {
+    EmptyInput,
+    /// The interval must start with one of `m`, `M`, `P`, `A` or `d`.
+    InvalidQuality,
+    /// The quality must be followed by a diatonic number of at least 1.
+    InvalidNumber,
+    /// The quality doesn't exist for the number, like a major 11th.
+    InvalidQualityForNumber,
+    /// The interval fits within an octave, so it's a [`SimpleInterval`].
+    UseSimpleInterval,
+    /// The number is larger than [`CompoundInterval::MAX_NUMBER`].
+    NumberTooLarge,
+}
+
+/// Parses a compound interval from its short name, a quality followed by a
+/// diatonic number, like "m9", "P12" or "A8". This is the same form that
+/// [`CompoundInterval`] is displayed in. Intervals wider than a double
+/// octave, like "M16", are parsed as [`CompoundInterval::Other`].
+///
+/// ```rust
+/// use note_lib::{CompoundInterval, ParseIntervalError};
+///
+/// assert_eq!(CompoundInterval::try_from("m9"), Ok(CompoundInterval::MinorNinth));
+/// assert_eq!(CompoundInterval::try_from("M3"), Err(ParseIntervalError::UseSimpleInterval));
+/// ```
+impl TryFrom<&str> for CompoundInterval {
+    type Error = ParseIntervalError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let mut chars = value.chars();
+
+        let quality = match chars.next() {
+            None => return Err(ParseIntervalError::EmptyInput),
+            Some('m') => IntervalQuality::Minor,
+            Some('M') => IntervalQuality::Major,
+            Some('P') => IntervalQuality::Perfect,
+            Some('A') => IntervalQuality::Augmented,
+            Some('d') => IntervalQuality::Diminished,
+            Some(_) => return Err(ParseIntervalError::InvalidQuality),
+        };
+
+        let number: i32 = chars
+            .as_str()
+            .parse()
+            .ok()
+            .filter(|number| *number >= 1)
+            .ok_or(ParseIntervalError::InvalidNumber)?;
+        if number > CompoundInterval::MAX_NUMBER {
+            return Err(ParseIntervalError::NumberTooLarge);
+        }
+
+        if let Some(named) = CompoundInterval::iter()
+            .filter(|interval| !matches!(interval, CompoundInterval::Other(_)))
+            .find(|interval| interval.to_string() == value)
+        {
+            return Ok(named);
+        }
+
+        // Split the number into octaves and a simple interval number, so a
+        // 16th is two octaves and a second.
+        let octaves = (number - 1) / 7;
+        let simple_number = SimpleIntervalNumber::iter()
+            .nth(((number - 1) % 7) as usize)
+            .unwrap();
+
+        // Unisons that can't be made, like a diminished unison, are made as an
+        // octave on top of one less octave instead.
+        let (top_interval, octaves) =
+            match SimpleInterval::from_quality_and_number(quality, simple_number) {
+                Ok(interval) => (interval, octaves),
+                Err(_) if simple_number == SimpleIntervalNumber::Unison => {
+                    SimpleInterval::from_quality_and_number(quality, SimpleIntervalNumber::Octave)
+                        .map(|interval| (interval, octaves - 1))
+                        .map_err(|_| ParseIntervalError::InvalidQualityForNumber)?
+                }
+                Err(_) => return Err(ParseIntervalError::InvalidQualityForNumber),
+            };
+
+        // Everything up to an octave, and every named compound interval, has
+        // already been handled above.
+        if number <= 15 {
+            return Err(if number <= 8 {
+                ParseIntervalError::UseSimpleInterval
+            } else {
+                ParseIntervalError::InvalidQualityForNumber
+            });
+        }
+
+        let mut interval_stack = vec![SimpleInterval::PerfectOctave; octaves as usize];
+        interval_stack.push(top_interval);
+        Ok(CompoundInterval::Other(OtherCompoundInterval::new(
+            interval_stack,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn parses_compound_intervals() {
+        assert_eq!(
+            CompoundInterval::try_from("m9"),
+            Ok(CompoundInterval::MinorNinth)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("M13"),
+            Ok(CompoundInterval::MajorThirteenth)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("P11"),
+            Ok(CompoundInterval::PerfectEleventh)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("P12"),
+            Ok(CompoundInterval::PerfectTwelfth)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("A8"),
+            Ok(CompoundInterval::AugmentedOctave)
+        );
+
+        let m16 = CompoundInterval::try_from("M16").unwrap();
+        assert_eq!(m16.to_string(), "M16");
+        assert_eq!(m16.semitones(), 26);
+        assert_eq!(CompoundInterval::try_from("d22").unwrap().semitones(), 35);
+
+        assert_eq!(
+            CompoundInterval::try_from("M3"),
+            Err(ParseIntervalError::UseSimpleInterval)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("P8"),
+            Err(ParseIntervalError::UseSimpleInterval)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("M11"),
+            Err(ParseIntervalError::InvalidQualityForNumber)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("P16"),
+            Err(ParseIntervalError::InvalidQualityForNumber)
+        );
+        assert_eq!(
+            CompoundInterval::try_from(""),
+            Err(ParseIntervalError::EmptyInput)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("X9"),
+            Err(ParseIntervalError::InvalidQuality)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("M"),
+            Err(ParseIntervalError::InvalidNumber)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("m0"),
+            Err(ParseIntervalError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn rejects_numbers_past_the_note_range() {
+        let widest = CompoundInterval::try_from("M77").unwrap();
+        assert_eq!(widest.semitones(), 131);
+        assert_eq!(widest.semitones(), 12 * (Octave::MAX - Octave::MIN) + 11);
+
+        assert_eq!(
+            CompoundInterval::try_from("m78"),
+            Err(ParseIntervalError::NumberTooLarge)
+        );
+        assert_eq!(
+            CompoundInterval::try_from("m999999999"),
+            Err(ParseIntervalError::NumberTooLarge)
+        );
+    }
+
+    #[test]
+    fn round_trips_through_names() {
+        for interval in CompoundInterval::iter()
+            .filter(|interval| !matches!(interval, CompoundInterval::Other(_)))
+        {
+            assert_eq!(
+                CompoundInterval::try_from(interval.to_string().as_str()),
+                Ok(interval)
+            );
+        }
+    }
+}
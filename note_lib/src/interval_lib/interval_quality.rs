@@ -1,3 +1,5 @@
+use crate::{Semitone, SimpleIntervalNumber};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::EnumIter)]
 pub enum IntervalQuality {
     Perfect,
@@ -7,6 +9,50 @@ pub enum IntervalQuality {
     Diminished,
 }
 
+impl IntervalQuality {
+    /// Gets how many semitones an interval of this quality and `number` is
+    /// altered from the natural interval with that number, which is the one
+    /// found in the major scale (see [`SimpleIntervalNumber::natural_semitones`]).
+    /// A minor third is -1, and a diminished fifth is also -1 since fifths
+    /// have no minor quality.
+    ///
+    /// Qualities that don't exist for `number` are treated as the nearest one
+    /// that does, so a "major" fifth is 0 like a perfect fifth.
+    ///
+    /// ```rust
+    /// use note_lib::{IntervalQuality, SimpleIntervalNumber};
+    ///
+    /// assert_eq!(IntervalQuality::Diminished.semitone_delta_from_natural(SimpleIntervalNumber::Seventh), -2);
+    /// assert_eq!(IntervalQuality::Diminished.semitone_delta_from_natural(SimpleIntervalNumber::Fifth), -1);
+    /// assert_eq!(IntervalQuality::Augmented.semitone_delta_from_natural(SimpleIntervalNumber::Fourth), 1);
+    /// ```
+    pub fn semitone_delta_from_natural(&self, number: SimpleIntervalNumber) -> Semitone {
+        match self {
+            IntervalQuality::Perfect | IntervalQuality::Major => 0,
+            IntervalQuality::Minor => -1,
+            IntervalQuality::Augmented => 1,
+            IntervalQuality::Diminished if number.is_perfect() => -1,
+            IntervalQuality::Diminished => -2,
+        }
+    }
+
+    /// Gets a value for sorting qualities from most lowered to most raised,
+    /// regardless of interval number. This is the alteration from natural for
+    /// an imperfect interval, like a third: diminished is -2, minor is -1,
+    /// major and perfect are both 0, and augmented is 1.
+    ///
+    /// Unlike the [`PartialOrd`] implementation, every pair of qualities can
+    /// be compared this way.
+    pub fn alteration_value(&self) -> i32 {
+        match self {
+            IntervalQuality::Diminished => -2,
+            IntervalQuality::Minor => -1,
+            IntervalQuality::Perfect | IntervalQuality::Major => 0,
+            IntervalQuality::Augmented => 1,
+        }
+    }
+}
+
 impl std::fmt::Display for IntervalQuality {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = if !f.alternate() {
@@ -31,6 +77,10 @@ impl std::fmt::Display for IntervalQuality {
     }
 }
 
+/// Orders qualities from diminished up to augmented. Perfect intervals have
+/// no major or minor form and the other way around, so [`IntervalQuality::Perfect`]
+/// can't be compared with [`IntervalQuality::Major`] or [`IntervalQuality::Minor`].
+/// Use [`IntervalQuality::alteration_value`] when a total order is needed.
 impl PartialOrd for IntervalQuality {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match self {
@@ -73,3 +123,53 @@ impl PartialOrd for IntervalQuality {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::SimpleInterval;
+
+    #[test]
+    fn sorts_by_alteration_value() {
+        let mut qualities: Vec<IntervalQuality> = IntervalQuality::iter().collect();
+        qualities.sort_by_key(|quality| quality.alteration_value());
+
+        assert_eq!(
+            qualities,
+            vec![
+                IntervalQuality::Diminished,
+                IntervalQuality::Minor,
+                IntervalQuality::Perfect,
+                IntervalQuality::Major,
+                IntervalQuality::Augmented,
+            ]
+        );
+
+        // The alteration value agrees with every order the partial order defines.
+        for a in IntervalQuality::iter() {
+            for b in IntervalQuality::iter() {
+                if let Some(ordering) = a.partial_cmp(&b) {
+                    if ordering != std::cmp::Ordering::Equal {
+                        assert_eq!(a.alteration_value().cmp(&b.alteration_value()), ordering);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn delta_from_natural_matches_interval_semitones() {
+        for interval in SimpleInterval::iter() {
+            let number = interval.interval_number();
+
+            assert_eq!(
+                number.natural_semitones() + interval.quality().semitone_delta_from_natural(number),
+                interval.semitones(),
+                "{interval:?}"
+            );
+        }
+    }
+}
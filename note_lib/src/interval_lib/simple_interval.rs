@@ -1,6 +1,6 @@
 use std::ops::{Add, Sub};
 
-use crate::{IntervalQuality, Semitone, SimpleIntervalFromSemitones};
+use crate::{AbstractNote, Cents, IntervalQuality, Semitone, SimpleIntervalFromSemitones};
 
 #[derive(
     Debug,
@@ -24,6 +24,42 @@ pub enum SimpleIntervalNumber {
     Octave,
 }
 
+impl SimpleIntervalNumber {
+    /// Gets the semitones spanned by the perfect or major interval with this
+    /// number, which is the span between natural notes in a major scale.
+    ///
+    /// ```rust
+    /// use note_lib::SimpleIntervalNumber;
+    ///
+    /// assert_eq!(SimpleIntervalNumber::Third.natural_semitones(), 4);
+    /// assert_eq!(SimpleIntervalNumber::Fifth.natural_semitones(), 7);
+    /// ```
+    pub fn natural_semitones(&self) -> Semitone {
+        match self {
+            SimpleIntervalNumber::Unison => 0,
+            SimpleIntervalNumber::Second => 2,
+            SimpleIntervalNumber::Third => 4,
+            SimpleIntervalNumber::Fourth => 5,
+            SimpleIntervalNumber::Fifth => 7,
+            SimpleIntervalNumber::Sixth => 9,
+            SimpleIntervalNumber::Seventh => 11,
+            SimpleIntervalNumber::Octave => 12,
+        }
+    }
+
+    /// Whether intervals with this number are perfect, rather than major or
+    /// minor. Unisons, fourths, fifths and octaves are perfect.
+    pub fn is_perfect(&self) -> bool {
+        matches!(
+            self,
+            SimpleIntervalNumber::Unison
+                | SimpleIntervalNumber::Fourth
+                | SimpleIntervalNumber::Fifth
+                | SimpleIntervalNumber::Octave
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InvalidSimpleIntervalError {
     InvalidPerfectNumber,
@@ -31,6 +67,12 @@ pub enum InvalidSimpleIntervalError {
     InvalidDiminishedNumber,
     InvalidMajorNumber,
     InvalidMinorNumber,
+    /// The interval would be smaller than diminished, which can't be
+    /// represented.
+    DoublyDiminished,
+    /// The interval would be larger than augmented, which can't be
+    /// represented.
+    DoublyAugmented,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, strum_macros::EnumIter)]
@@ -242,6 +284,82 @@ impl SimpleInterval {
         SimpleIntervalFromSemitones::new(semitones)
     }
 
+    /// Gets the interval with the given number that spans `semitones`, working
+    /// out its quality from how far `semitones` is from the natural interval
+    /// with that number (see [`SimpleIntervalNumber::natural_semitones`]).
+    /// This is the inverse of getting an interval's number and semitones.
+    ///
+    /// Fails with [`InvalidSimpleIntervalError::DoublyDiminished`] or
+    /// [`InvalidSimpleIntervalError::DoublyAugmented`] if the interval would be
+    /// altered past diminished or augmented, or with the error of
+    /// [`SimpleInterval::from_quality_and_number`] if the interval isn't
+    /// simple, like a diminished unison.
+    ///
+    /// ```rust
+    /// use note_lib::{InvalidSimpleIntervalError, SimpleInterval, SimpleIntervalNumber};
+    ///
+    /// let third = |semitones| SimpleInterval::from_diatonic_and_semitones(SimpleIntervalNumber::Third, semitones);
+    ///
+    /// assert_eq!(third(4), Ok(SimpleInterval::MajorThird));
+    /// assert_eq!(third(2), Ok(SimpleInterval::DiminishedThird));
+    /// assert_eq!(third(1), Err(InvalidSimpleIntervalError::DoublyDiminished));
+    /// ```
+    pub fn from_diatonic_and_semitones(
+        number: SimpleIntervalNumber,
+        semitones: Semitone,
+    ) -> Result<SimpleInterval, InvalidSimpleIntervalError> {
+        let alteration = semitones - number.natural_semitones();
+
+        let quality = match (number.is_perfect(), alteration) {
+            (true, 0) => IntervalQuality::Perfect,
+            (false, 0) => IntervalQuality::Major,
+            (false, -1) => IntervalQuality::Minor,
+            (true, -1) | (false, -2) => IntervalQuality::Diminished,
+            (_, 1) => IntervalQuality::Augmented,
+            (_, alteration) if alteration < 0 => {
+                return Err(InvalidSimpleIntervalError::DoublyDiminished)
+            }
+            _ => return Err(InvalidSimpleIntervalError::DoublyAugmented),
+        };
+
+        SimpleInterval::from_quality_and_number(quality, number)
+    }
+
+    /// Names the interval up from `low` to `high` by the letters of both
+    /// notes as well as the semitones between them, so C to E# is an
+    /// augmented third rather than a perfect fourth. Intervals that would need
+    /// a doubly augmented or doubly diminished quality fall back to
+    /// [`SimpleInterval::from_semitones`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, SimpleInterval};
+    ///
+    /// let note = |name: &str| AbstractNote::try_from(name).unwrap();
+    ///
+    /// assert_eq!(SimpleInterval::from_note_names(note("C"), note("E#")), SimpleInterval::AugmentedThird);
+    /// assert_eq!(SimpleInterval::from_note_names(note("Db"), note("F#")), SimpleInterval::AugmentedThird);
+    /// ```
+    pub fn from_note_names(low: AbstractNote, high: AbstractNote) -> SimpleInterval {
+        let mut interval_number = low.diatonic_interval_to(high);
+
+        let semitones =
+            (high.chromatic_index() as Semitone - low.chromatic_index() as Semitone).rem_euclid(12);
+        // Wrap the alteration into -6..=5 so that a spelling crossing C, like
+        // Cb to B, isn't mistaken for an alteration of almost an octave.
+        let alteration = (semitones - interval_number.natural_semitones() + 6).rem_euclid(12) - 6;
+
+        // Going up from C# to C is a diminished octave, not a diminished unison.
+        if interval_number == SimpleIntervalNumber::Unison && alteration < 0 {
+            interval_number = SimpleIntervalNumber::Octave;
+        }
+
+        SimpleInterval::from_diatonic_and_semitones(
+            interval_number,
+            interval_number.natural_semitones() + alteration,
+        )
+        .unwrap_or_else(|_| SimpleInterval::from_semitones(semitones).interval)
+    }
+
     /// Get the count of semitones this interval represents.
     pub fn semitones(&self) -> Semitone {
         match self {
@@ -261,6 +379,59 @@ impl SimpleInterval {
         }
     }
 
+    /// Get the size of this interval in cents, in equal temperament.
+    ///
+    /// ```rust
+    /// use note_lib::{Cents, SimpleInterval};
+    ///
+    /// assert_eq!(SimpleInterval::PerfectFifth.cents(), Cents::new(700.0));
+    /// ```
+    pub fn cents(&self) -> Cents {
+        Cents::from_semitones(self.semitones() as f64)
+    }
+
+    /// Gets the frequency ratio of this interval in 5-limit just intonation,
+    /// as `(numerator, denominator)`. Just intervals are built from small
+    /// whole number ratios, so a perfect fifth is exactly 3:2 rather than the
+    /// slightly narrower equal tempered fifth.
+    ///
+    /// ```rust
+    /// use note_lib::SimpleInterval;
+    ///
+    /// assert_eq!(SimpleInterval::PerfectFifth.just_frequency_ratio(), (3, 2));
+    /// assert_eq!(SimpleInterval::MajorThird.just_frequency_ratio(), (5, 4));
+    /// ```
+    pub fn just_frequency_ratio(&self) -> (u32, u32) {
+        match self {
+            Self::PerfectUnison => (1, 1),
+            Self::DiminishedSecond => (128, 125),
+            Self::AugmentedUnison => (25, 24),
+            Self::MinorSecond => (16, 15),
+            Self::MajorSecond => (9, 8),
+            Self::DiminishedThird => (256, 225),
+            Self::AugmentedSecond => (75, 64),
+            Self::MinorThird => (6, 5),
+            Self::MajorThird => (5, 4),
+            Self::DiminishedFourth => (32, 25),
+            Self::AugmentedThird => (125, 96),
+            Self::PerfectFourth => (4, 3),
+            Self::DiminishedFifth => (64, 45),
+            Self::AugmentedFourth => (45, 32),
+            Self::PerfectFifth => (3, 2),
+            Self::DiminishedSixth => (192, 125),
+            Self::AugmentedFifth => (25, 16),
+            Self::MinorSixth => (8, 5),
+            Self::MajorSixth => (5, 3),
+            Self::DiminishedSeventh => (128, 75),
+            Self::AugmentedSixth => (225, 128),
+            Self::MinorSeventh => (9, 5),
+            Self::MajorSeventh => (15, 8),
+            Self::DiminishedOctave => (48, 25),
+            Self::AugmentedSeventh => (125, 64),
+            Self::PerfectOctave => (2, 1),
+        }
+    }
+
     /// Returns the interval number of this interval. For example, a
     /// [`Interval::MinorThird`] has an interval number of [`IntervalNumber::Third`].
     ///
@@ -725,4 +896,82 @@ mod tests {
             SimpleInterval::MajorThird
         );
     }
+
+    #[test]
+    fn names_intervals_from_note_names() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(
+            SimpleInterval::from_note_names(note("C"), note("E#")),
+            SimpleInterval::AugmentedThird
+        );
+        assert_eq!(
+            SimpleInterval::from_note_names(note("Db"), note("F#")),
+            SimpleInterval::AugmentedThird
+        );
+        assert_eq!(
+            SimpleInterval::from_note_names(note("C"), note("F")),
+            SimpleInterval::PerfectFourth
+        );
+        assert_eq!(
+            SimpleInterval::from_note_names(note("B"), note("F")),
+            SimpleInterval::DiminishedFifth
+        );
+    }
+
+    #[test]
+    fn cents_match_semitones() {
+        for interval in SimpleInterval::iter() {
+            assert_eq!(
+                interval.cents().to_semitones_f64().round() as Semitone,
+                interval.semitones(),
+                "{interval:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn builds_intervals_from_number_and_semitones() {
+        use SimpleIntervalNumber::*;
+
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Third, 4),
+            Ok(SimpleInterval::MajorThird)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Third, 3),
+            Ok(SimpleInterval::MinorThird)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Third, 5),
+            Ok(SimpleInterval::AugmentedThird)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Third, 2),
+            Ok(SimpleInterval::DiminishedThird)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Third, 1),
+            Err(InvalidSimpleIntervalError::DoublyDiminished)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Fifth, 5),
+            Err(InvalidSimpleIntervalError::DoublyDiminished)
+        );
+        assert_eq!(
+            SimpleInterval::from_diatonic_and_semitones(Fourth, 7),
+            Err(InvalidSimpleIntervalError::DoublyAugmented)
+        );
+
+        // Every interval can be rebuilt from its number and semitones.
+        for interval in SimpleInterval::iter() {
+            assert_eq!(
+                SimpleInterval::from_diatonic_and_semitones(
+                    interval.interval_number(),
+                    interval.semitones()
+                ),
+                Ok(interval)
+            );
+        }
+    }
 }
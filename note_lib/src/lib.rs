@@ -4,6 +4,8 @@ mod note_lib;
 mod primatives;
 mod scale_lib;
 
+pub mod prelude;
+
 //TODO: Should these exports preserve namespace?
 pub use chord_lib::*;
 pub use interval_lib::*;
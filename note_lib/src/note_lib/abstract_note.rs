@@ -3,13 +3,16 @@ use std::{
     ops::{Add, Sub},
 };
 
-use crate::{Semitone, SimpleInterval};
+use crate::{
+    Octave, Scale, ScaleDegree, ScaleMode, Semitone, SimpleInterval, SimpleIntervalNumber,
+};
 
 use super::{ModifierPreference, Note, NoteModifier, RawNote};
 
 /// Represents a note that has a modifier, but no octave defined.
 /// This is typically used when talking about [`super::super::ScaleMode`]s
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbstractNote {
     pub raw_note: RawNote,
     pub modifier: NoteModifier,
@@ -17,25 +20,25 @@ pub struct AbstractNote {
 
 impl AbstractNote {
     /// Creates an octave-placed note using this note's raw note and modifier.
-    pub fn at_octave(&self, octave: i32) -> Note {
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, F};
+    ///
+    /// let f_sharp = AbstractNote::try_from("F#").unwrap();
+    /// assert_eq!(f_sharp.to_note(3), Note::new(F, 3, NoteModifier::Sharp));
+    /// ```
+    pub fn to_note(&self, octave: impl Into<Octave>) -> Note {
         Note::new(self.raw_note, octave, self.modifier)
     }
 
+    #[deprecated(since = "0.1.0", note = "use `to_note` instead")]
+    pub fn at_octave(&self, octave: impl Into<Octave>) -> Note {
+        self.to_note(octave)
+    }
+
     /// Gets the abstract note's interval from C
     pub fn interval_from_c(&self) -> SimpleInterval {
-        let mut semitones_from_c = 0;
-        let mut current_note = self.raw_note;
-        while current_note != RawNote::C {
-            match current_note {
-                RawNote::C => (),
-                RawNote::Incongruent(_) => unreachable!(),
-                _ => {
-                    let (prev_note, semitones_to_prev_note) = current_note.prev_note();
-                    current_note = prev_note;
-                    semitones_from_c += semitones_to_prev_note as u32;
-                }
-            }
-        }
+        let semitones_from_c = self.raw_note.chromatic_index();
 
         let modifier_semitone_adjustment = match self.modifier {
             NoteModifier::Sharp => 1,
@@ -49,6 +52,99 @@ impl AbstractNote {
             .interval
     }
 
+    /// Gets the pitch class of this note, which is the count of semitones
+    /// above C wrapped within one octave (0 through 11). Enharmonically
+    /// equivalent notes share a pitch class, so B# and C are both 0.
+    ///
+    /// ```rust
+    /// use note_lib::AbstractNote;
+    ///
+    /// assert_eq!(AbstractNote::try_from("C").unwrap().chromatic_index(), 0);
+    /// assert_eq!(AbstractNote::try_from("B#").unwrap().chromatic_index(), 0);
+    /// assert_eq!(AbstractNote::try_from("Cb").unwrap().chromatic_index(), 11);
+    /// assert_eq!(AbstractNote::try_from("F#").unwrap().chromatic_index(), 6);
+    /// ```
+    pub fn chromatic_index(&self) -> u8 {
+        self.interval_from_c().semitones().rem_euclid(12) as u8
+    }
+
+    /// Counts the semitones going up from `tonic` to this note, wrapping
+    /// within an octave, so the result is 0 to 11. Enharmonic spellings are
+    /// the same distance from the tonic.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, C, E, G};
+    ///
+    /// assert_eq!(AbstractNote::from(E).relative_to_tonic(AbstractNote::from(C)), 4);
+    /// assert_eq!(AbstractNote::from(C).relative_to_tonic(AbstractNote::from(G)), 5);
+    /// ```
+    pub fn relative_to_tonic(&self, tonic: AbstractNote) -> i32 {
+        (self.chromatic_index() as i32 - tonic.chromatic_index() as i32).rem_euclid(12)
+    }
+
+    /// Gets all 12 pitch classes from C up to B, spelling the black keys with
+    /// sharps or flats depending on `modifier_preference`.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ModifierPreference};
+    ///
+    /// let flats = AbstractNote::all_chromatic(ModifierPreference::Flat).map(|note| note.to_string());
+    /// assert_eq!(
+    ///     flats,
+    ///     ["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"]
+    /// );
+    /// ```
+    pub fn all_chromatic(modifier_preference: ModifierPreference) -> [AbstractNote; 12] {
+        std::array::from_fn(|pitch_class| {
+            Self::from_midi_pitch_class(pitch_class as u8, modifier_preference)
+        })
+    }
+
+    /// Gets the note of a pitch class, counted in semitones up from C, so 0 is
+    /// C and 11 is B. This is the inverse of [`AbstractNote::chromatic_index`].
+    /// Black keys are spelled with sharps or flats depending on
+    /// `modifier_preference`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pitch_class` is greater than 11.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ModifierPreference};
+    ///
+    /// let note = AbstractNote::from_midi_pitch_class(1, ModifierPreference::Flat);
+    /// assert_eq!(note.to_string(), "Db");
+    /// ```
+    pub fn from_midi_pitch_class(
+        pitch_class: u8,
+        modifier_preference: ModifierPreference,
+    ) -> AbstractNote {
+        assert!(
+            pitch_class < 12,
+            "pitch class {} is not 0 to 11",
+            pitch_class
+        );
+
+        Self::from_interval_from_c(
+            SimpleInterval::from_semitones(pitch_class as Semitone).interval,
+            modifier_preference,
+        )
+    }
+
+    /// Gets the seven natural notes from C up to B.
+    pub fn all_natural() -> [AbstractNote; 7] {
+        [
+            RawNote::C,
+            RawNote::D,
+            RawNote::E,
+            RawNote::F,
+            RawNote::G,
+            RawNote::A,
+            RawNote::B,
+        ]
+        .map(AbstractNote::from)
+    }
+
     pub fn from_interval_from_c(
         interval: SimpleInterval,
         modifier_preference: ModifierPreference,
@@ -84,6 +180,27 @@ impl AbstractNote {
         self.add_semitones(interval.semitones())
     }
 
+    /// Adds an interval, giving the result the letter name the interval calls
+    /// for, so a major third above F# is A# rather than Bb. If that letter
+    /// would need more than a double sharp or double flat, the spelling from
+    /// [`AbstractNote::add_interval`] is used instead.
+    pub(crate) fn spelled_add_interval(&self, interval: SimpleInterval) -> Self {
+        let mut raw_note = self.raw_note;
+        for _ in 1..(interval.interval_number() as usize) {
+            raw_note = raw_note.next_note().0;
+        }
+
+        let target_pitch_class = self.chromatic_index() as Semitone + interval.semitones();
+        let natural_pitch_class = AbstractNote::from(raw_note).chromatic_index() as Semitone;
+        // Wrap the difference so that notes across the B/C boundary are compared correctly.
+        let modifier_semitones = (target_pitch_class - natural_pitch_class + 6).rem_euclid(12) - 6;
+
+        match NoteModifier::from_semitones(modifier_semitones) {
+            Some(modifier) => AbstractNote { raw_note, modifier },
+            None => self.add_interval(interval),
+        }
+    }
+
     pub fn add_semitones(&self, semitones: Semitone) -> Self {
         if semitones == 0 {
             return *self;
@@ -95,11 +212,219 @@ impl AbstractNote {
             self.modifier,
         )
     }
+
+    /// Spells this note with `target_modifier` if an enharmonic equivalent
+    /// with that modifier exists, otherwise returns the note unchanged. This
+    /// is the method form of [`bias_abstract_note_to_enharmonic_equivalent`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, NoteModifier};
+    ///
+    /// let c_sharp = AbstractNote::try_from("C#").unwrap();
+    /// assert_eq!(c_sharp.respell(NoteModifier::Flat), AbstractNote::try_from("Db").unwrap());
+    /// ```
+    pub fn respell(&self, target_modifier: NoteModifier) -> AbstractNote {
+        bias_abstract_note_to_enharmonic_equivalent(self, target_modifier)
+    }
+
+    /// Respells this note with a sharp, like Db to C#.
+    pub fn to_sharp_spelling(&self) -> AbstractNote {
+        self.respell(NoteModifier::Sharp)
+    }
+
+    /// Respells this note with a flat, like C# to Db.
+    pub fn to_flat_spelling(&self) -> AbstractNote {
+        self.respell(NoteModifier::Flat)
+    }
+
+    /// Gets the note one semitone above this one, wrapping from B to C. Black
+    /// keys are spelled with sharps or flats depending on `modifier_preference`,
+    /// and white keys are always natural, so E# steps up to F#.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ModifierPreference};
+    ///
+    /// let c = AbstractNote::try_from("C").unwrap();
+    /// assert_eq!(c.next_chromatic(ModifierPreference::Sharp), AbstractNote::try_from("C#").unwrap());
+    /// assert_eq!(c.next_chromatic(ModifierPreference::Flat), AbstractNote::try_from("Db").unwrap());
+    /// ```
+    pub fn next_chromatic(&self, modifier_preference: ModifierPreference) -> AbstractNote {
+        Self::from_midi_pitch_class((self.chromatic_index() + 1) % 12, modifier_preference)
+    }
+
+    /// Gets the note one semitone below this one, wrapping from C to B. See
+    /// [`AbstractNote::next_chromatic`].
+    pub fn prev_chromatic(&self, modifier_preference: ModifierPreference) -> AbstractNote {
+        Self::from_midi_pitch_class((self.chromatic_index() + 11) % 12, modifier_preference)
+    }
+
+    /// Gets the next note of the given scale above this note, wrapping from
+    /// the last note of the scale back to the root. If this note is not in the scale,
+    /// the nearest scale note above it is used instead.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ScaleMode, C, E, F};
+    ///
+    /// let next = AbstractNote::from(E).next_note_diatonic(AbstractNote::from(C), ScaleMode::Ionian);
+    /// assert_eq!(next, AbstractNote::from(F));
+    /// ```
+    pub fn next_note_diatonic(&self, scale_root: AbstractNote, mode: ScaleMode) -> AbstractNote {
+        self.step_diatonic(scale_root, mode, 1)
+    }
+
+    /// Gets the previous note of the given scale below this note, wrapping
+    /// from the root back to the last note of the scale. If this note is not in the
+    /// scale, the nearest scale note below it is used instead.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ScaleMode, B, C};
+    ///
+    /// let prev = AbstractNote::from(C).prev_note_diatonic(AbstractNote::from(C), ScaleMode::Ionian);
+    /// assert_eq!(prev, AbstractNote::from(B));
+    /// ```
+    pub fn prev_note_diatonic(&self, scale_root: AbstractNote, mode: ScaleMode) -> AbstractNote {
+        self.step_diatonic(scale_root, mode, -1)
+    }
+
+    fn step_diatonic(&self, scale_root: AbstractNote, mode: ScaleMode, steps: i32) -> AbstractNote {
+        let scale_notes = Scale::new(scale_root, mode).all_notes();
+        let pitch_class = self.chromatic_index() as Semitone;
+
+        match scale_notes
+            .iter()
+            .position(|note| note.chromatic_index() as Semitone == pitch_class)
+        {
+            Some(index) => {
+                scale_notes[(index as i32 + steps).rem_euclid(scale_notes.len() as i32) as usize]
+            }
+            None => {
+                // Not in the scale, so find the closest scale note in the
+                // direction we're stepping.
+                scale_notes
+                    .into_iter()
+                    .min_by_key(|note| {
+                        ((note.chromatic_index() as Semitone - pitch_class) * steps.signum())
+                            .rem_euclid(12)
+                    })
+                    .unwrap_or(*self)
+            }
+        }
+    }
+
+    /// Gets the notes on either side of this one in the circle of fifths, as
+    /// `(subdominant, dominant)`. The subdominant is a perfect fifth below this
+    /// note, and the dominant is a perfect fifth above it.
+    ///
+    /// ```rust
+    /// use note_lib::AbstractNote;
+    ///
+    /// let (subdominant, dominant) = AbstractNote::try_from("C").unwrap().cycle_of_fifths_neighbors();
+    ///
+    /// assert_eq!(subdominant, AbstractNote::try_from("F").unwrap());
+    /// assert_eq!(dominant, AbstractNote::try_from("G").unwrap());
+    /// ```
+    pub fn cycle_of_fifths_neighbors(&self) -> (AbstractNote, AbstractNote) {
+        // A fifth below is the same note as a fourth above.
+        (
+            ScaleMode::Ionian.spelled_note_at_degree(*self, ScaleDegree::Fourth),
+            ScaleMode::Ionian.spelled_note_at_degree(*self, ScaleDegree::Fifth),
+        )
+    }
+
+    /// Gets where this note sits on the circle of fifths, counting fifths up
+    /// from C. Flat notes are counted going down instead, so G is 1, F is -1,
+    /// F# is 6 and Gb is -6. For a major key this is how many sharps (or, if
+    /// negative, flats) its key signature has.
+    ///
+    /// Panics for incongruent notes, which aren't on the circle of fifths.
+    ///
+    /// ```rust
+    /// use note_lib::AbstractNote;
+    ///
+    /// assert_eq!(AbstractNote::try_from("D").unwrap().cycle_of_fifths_position(), 2);
+    /// assert_eq!(AbstractNote::try_from("Eb").unwrap().cycle_of_fifths_position(), -3);
+    /// ```
+    pub fn cycle_of_fifths_position(&self) -> i32 {
+        let natural_position = match self.raw_note {
+            RawNote::F => -1,
+            RawNote::C => 0,
+            RawNote::G => 1,
+            RawNote::D => 2,
+            RawNote::A => 3,
+            RawNote::E => 4,
+            RawNote::B => 5,
+            RawNote::Incongruent(_) => {
+                panic!("An incongruent note has no place on the circle of fifths.")
+            }
+        };
+
+        // Each sharp moves a note seven fifths clockwise, and each flat seven
+        // fifths counter-clockwise.
+        natural_position + 7 * self.modifier.semitones()
+    }
+
+    /// Counts the letter names going up from this note to `other`, ignoring
+    /// modifiers. The count wraps past B, so G up to F is a seventh. Notes with
+    /// the same letter are a unison.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, SimpleIntervalNumber, C, E, F, G};
+    ///
+    /// let c = AbstractNote::from(C);
+    /// assert_eq!(c.diatonic_interval_to(AbstractNote::from(E)), SimpleIntervalNumber::Third);
+    ///
+    /// let g = AbstractNote::from(G);
+    /// assert_eq!(g.diatonic_interval_to(AbstractNote::from(F)), SimpleIntervalNumber::Seventh);
+    /// ```
+    pub fn diatonic_interval_to(&self, other: AbstractNote) -> SimpleIntervalNumber {
+        let mut letter_steps = 0;
+        let mut current_note = self.raw_note;
+        while current_note != other.raw_note {
+            current_note = current_note.next_note().0;
+            letter_steps += 1;
+        }
+
+        match letter_steps {
+            0 => SimpleIntervalNumber::Unison,
+            1 => SimpleIntervalNumber::Second,
+            2 => SimpleIntervalNumber::Third,
+            3 => SimpleIntervalNumber::Fourth,
+            4 => SimpleIntervalNumber::Fifth,
+            5 => SimpleIntervalNumber::Sixth,
+            _ => SimpleIntervalNumber::Seventh,
+        }
+    }
+
+    /// Gets the interval up from this note to `other`, named by the letters of
+    /// both notes rather than just the semitones between them. C to E# is an
+    /// augmented third, even though it sounds the same as a perfect fourth.
+    ///
+    /// Intervals that would need a doubly augmented or doubly diminished
+    /// quality can't be represented, so those fall back to the enharmonic
+    /// interval from the semitones alone.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, SimpleInterval};
+    ///
+    /// let c = AbstractNote::try_from("C").unwrap();
+    /// let e_sharp = AbstractNote::try_from("E#").unwrap();
+    ///
+    /// assert_eq!(c.correctly_spelled_interval_to(e_sharp), SimpleInterval::AugmentedThird);
+    /// ```
+    pub fn correctly_spelled_interval_to(&self, other: AbstractNote) -> SimpleInterval {
+        SimpleInterval::from_note_names(*self, other)
+    }
 }
 
+/// Displays the note name with an ASCII modifier, like `C#`. The alternate
+/// form (`{:#}`) uses the musical symbol for the modifier instead, like `C♯`.
 impl Display for AbstractNote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.raw_note, self.modifier)
+        if f.alternate() {
+            write!(f, "{}{}", self.raw_note, self.modifier.to_unicode())
+        } else {
+            write!(f, "{}{}", self.raw_note, self.modifier)
+        }
     }
 }
 
@@ -157,6 +482,16 @@ impl Sub<SimpleInterval> for AbstractNote {
     }
 }
 
+/// Gets the interval up from `rhs` to `self`, so `E - C` is a major third and
+/// `C - E` is a minor sixth. See [`AbstractNote::correctly_spelled_interval_to`].
+impl Sub for AbstractNote {
+    type Output = SimpleInterval;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        rhs.correctly_spelled_interval_to(self)
+    }
+}
+
 impl From<Note> for AbstractNote {
     fn from(note: Note) -> Self {
         Self {
@@ -509,12 +844,10 @@ mod tests {
                 // and a perfect unison the same since in this abstract representation, they are enharmonically
                 // the same. For ex, B# and C are the same note, but B# is technically a perfect octave above C.
                 let similar_interval = match note_interval {
-                    SimpleInterval::PerfectUnison | SimpleInterval::PerfectOctave => {
-                        match biased_note_interval {
-                            SimpleInterval::PerfectUnison | SimpleInterval::PerfectOctave => true,
-                            _ => false,
-                        }
-                    }
+                    SimpleInterval::PerfectUnison | SimpleInterval::PerfectOctave => matches!(
+                        biased_note_interval,
+                        SimpleInterval::PerfectUnison | SimpleInterval::PerfectOctave
+                    ),
                     _ => note_interval == biased_note_interval,
                 };
 
@@ -522,4 +855,332 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn correctly_spelled_intervals() {
+        let interval = |from: &str, to: &str| {
+            AbstractNote::try_from(from)
+                .unwrap()
+                .correctly_spelled_interval_to(AbstractNote::try_from(to).unwrap())
+        };
+
+        assert_eq!(interval("C", "E#"), SimpleInterval::AugmentedThird);
+        assert_eq!(interval("Db", "F#"), SimpleInterval::AugmentedThird);
+        assert_eq!(interval("C", "Gb"), SimpleInterval::DiminishedFifth);
+        assert_eq!(interval("C", "F#"), SimpleInterval::AugmentedFourth);
+        assert_eq!(interval("C", "Eb"), SimpleInterval::MinorThird);
+        assert_eq!(interval("C", "D#"), SimpleInterval::AugmentedSecond);
+        assert_eq!(interval("A", "G"), SimpleInterval::MinorSeventh);
+        assert_eq!(interval("Cb", "B"), SimpleInterval::AugmentedSeventh);
+        assert_eq!(interval("C#", "C"), SimpleInterval::DiminishedOctave);
+        assert_eq!(interval("C", "C#"), SimpleInterval::AugmentedUnison);
+        assert_eq!(interval("E", "E"), SimpleInterval::PerfectUnison);
+    }
+
+    #[test]
+    fn diatonic_intervals_between_natural_notes() {
+        let naturals = [
+            RawNote::C,
+            RawNote::D,
+            RawNote::E,
+            RawNote::F,
+            RawNote::G,
+            RawNote::A,
+            RawNote::B,
+        ];
+        let numbers = [
+            SimpleIntervalNumber::Unison,
+            SimpleIntervalNumber::Second,
+            SimpleIntervalNumber::Third,
+            SimpleIntervalNumber::Fourth,
+            SimpleIntervalNumber::Fifth,
+            SimpleIntervalNumber::Sixth,
+            SimpleIntervalNumber::Seventh,
+        ];
+
+        for (from_index, from) in naturals.iter().enumerate() {
+            for (to_index, to) in naturals.iter().enumerate() {
+                let expected = numbers[(to_index + 7 - from_index) % 7];
+                let result =
+                    AbstractNote::from(*from).diatonic_interval_to(AbstractNote::from(*to));
+                assert_eq!(result, expected, "{} to {}", from, to);
+            }
+        }
+
+        // Modifiers don't change the letter distance.
+        let c_sharp = AbstractNote::try_from("C#").unwrap();
+        let e_flat = AbstractNote::try_from("Eb").unwrap();
+        assert_eq!(
+            c_sharp.diatonic_interval_to(e_flat),
+            SimpleIntervalNumber::Third
+        );
+    }
+
+    #[test]
+    fn steps_diatonically() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+        let c = note("C");
+
+        assert_eq!(
+            note("E").next_note_diatonic(c, ScaleMode::Ionian),
+            note("F")
+        );
+        assert_eq!(
+            note("B").next_note_diatonic(c, ScaleMode::Ionian),
+            note("C")
+        );
+        assert_eq!(c.prev_note_diatonic(c, ScaleMode::Ionian), note("B"));
+        assert_eq!(
+            note("F").prev_note_diatonic(c, ScaleMode::Ionian),
+            note("E")
+        );
+
+        // Steps are spelled by the scale, even from an enharmonic spelling.
+        let f = note("F");
+        assert_eq!(
+            note("A").next_note_diatonic(f, ScaleMode::Ionian),
+            note("Bb")
+        );
+        assert_eq!(
+            note("A#").prev_note_diatonic(f, ScaleMode::Ionian),
+            note("A")
+        );
+
+        // Notes outside the scale move to the nearest scale note.
+        assert_eq!(
+            note("C#").next_note_diatonic(c, ScaleMode::Ionian),
+            note("D")
+        );
+        assert_eq!(
+            note("C#").prev_note_diatonic(c, ScaleMode::Ionian),
+            note("C")
+        );
+    }
+
+    #[test]
+    fn gets_cycle_of_fifths_neighbors() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(
+            note("C").cycle_of_fifths_neighbors(),
+            (note("F"), note("G"))
+        );
+        assert_eq!(
+            note("F#").cycle_of_fifths_neighbors(),
+            (note("B"), note("C#"))
+        );
+        assert_eq!(
+            note("Bb").cycle_of_fifths_neighbors(),
+            (note("Eb"), note("F"))
+        );
+        assert_eq!(
+            note("B").cycle_of_fifths_neighbors(),
+            (note("E"), note("F#"))
+        );
+    }
+
+    #[test]
+    fn respells_notes() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(note("C#").respell(NoteModifier::Flat), note("Db"));
+        assert_eq!(note("Db").to_sharp_spelling(), note("C#"));
+        assert_eq!(note("A#").to_flat_spelling(), note("Bb"));
+        // Naturals with no sharp or flat spelling nearby stay as they are.
+        assert_eq!(note("D").to_sharp_spelling(), note("D"));
+        assert_eq!(note("E").to_sharp_spelling(), note("E"));
+    }
+
+    #[test]
+    fn lists_all_notes() {
+        let names =
+            |notes: &[AbstractNote]| notes.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            names(&AbstractNote::all_chromatic(ModifierPreference::Sharp)),
+            vec!["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"]
+        );
+        assert_eq!(
+            names(&AbstractNote::all_chromatic(ModifierPreference::Flat)),
+            vec!["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"]
+        );
+        for (pitch_class, note) in AbstractNote::all_chromatic(ModifierPreference::Flat)
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(note.chromatic_index() as usize, pitch_class);
+        }
+
+        assert_eq!(
+            names(&AbstractNote::all_natural()),
+            vec!["C", "D", "E", "F", "G", "A", "B"]
+        );
+    }
+
+    #[test]
+    fn displays_unicode_modifiers() {
+        let c_sharp = RawNote::C + NoteModifier::Sharp;
+        let b_flat = RawNote::B + NoteModifier::Flat;
+        let f_double_sharp = RawNote::F + NoteModifier::DoubleSharp;
+
+        assert_eq!(format!("{}", c_sharp), "C#");
+        assert_eq!(format!("{:#}", c_sharp), "C♯");
+        assert_eq!(format!("{}", b_flat), "Bb");
+        assert_eq!(format!("{:#}", b_flat), "B♭");
+        assert_eq!(format!("{:#}", f_double_sharp), "F𝄪");
+        assert_eq!(format!("{:#}", AbstractNote::from(RawNote::G)), "G");
+    }
+
+    #[test]
+    fn gets_cycle_of_fifths_position() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        let positions: Vec<i32> = [
+            "Gb", "Db", "Ab", "Eb", "Bb", "F", "C", "G", "D", "A", "E", "B", "F#", "C#",
+        ]
+        .into_iter()
+        .map(|name| note(name).cycle_of_fifths_position())
+        .collect();
+
+        assert_eq!(positions, (-6..=7).collect::<Vec<i32>>());
+        assert_eq!(note("Cb").cycle_of_fifths_position(), -7);
+        assert_eq!(note("Fx").cycle_of_fifths_position(), 13);
+    }
+
+    #[test]
+    fn converts_pitch_classes_to_notes() {
+        let names = |modifier_preference| {
+            (0..12)
+                .map(|pitch_class| {
+                    AbstractNote::from_midi_pitch_class(pitch_class, modifier_preference)
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(ModifierPreference::Sharp),
+            vec!["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"]
+        );
+        assert_eq!(
+            names(ModifierPreference::Flat),
+            vec!["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"]
+        );
+
+        for modifier_preference in [ModifierPreference::Sharp, ModifierPreference::Flat] {
+            for pitch_class in 0..12 {
+                let note = AbstractNote::from_midi_pitch_class(pitch_class, modifier_preference);
+                assert_eq!(note.chromatic_index(), pitch_class);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_pitch_classes_above_eleven() {
+        AbstractNote::from_midi_pitch_class(12, ModifierPreference::Sharp);
+    }
+
+    #[test]
+    fn measures_distance_from_tonic() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(note("E").relative_to_tonic(note("C")), 4);
+        assert_eq!(note("B").relative_to_tonic(note("C")), 11);
+        assert_eq!(note("C").relative_to_tonic(note("G")), 5);
+        assert_eq!(note("C").relative_to_tonic(note("C")), 0);
+        assert_eq!(note("Fb").relative_to_tonic(note("E")), 0);
+        assert_eq!(note("Cb").relative_to_tonic(note("B#")), 11);
+    }
+
+    #[test]
+    fn displays_compact_names() {
+        assert_eq!(format!("{}", AbstractNote::try_from("Eb").unwrap()), "Eb");
+        assert_eq!(format!("{}", AbstractNote::try_from("F##").unwrap()), "F##");
+
+        let modifiers = [
+            (NoteModifier::DoubleFlat, "bb"),
+            (NoteModifier::Flat, "b"),
+            (NoteModifier::Natural, ""),
+            (NoteModifier::Sharp, "#"),
+            (NoteModifier::DoubleSharp, "##"),
+        ];
+        for natural in AbstractNote::all_natural() {
+            for (modifier, symbol) in modifiers {
+                let note = AbstractNote {
+                    raw_note: natural.raw_note,
+                    modifier,
+                };
+                let name = format!("{}", note);
+
+                assert_eq!(name, format!("{}{}", natural.raw_note, symbol));
+                assert_eq!(AbstractNote::try_from(name.as_str()), Ok(note));
+            }
+        }
+    }
+
+    #[test]
+    fn steps_chromatically() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(
+            note("C").next_chromatic(ModifierPreference::Sharp),
+            note("C#")
+        );
+        assert_eq!(
+            note("C").next_chromatic(ModifierPreference::Flat),
+            note("Db")
+        );
+        assert_eq!(
+            note("B").next_chromatic(ModifierPreference::Sharp),
+            note("C")
+        );
+        assert_eq!(
+            note("E#").next_chromatic(ModifierPreference::Flat),
+            note("Gb")
+        );
+        assert_eq!(
+            note("C").prev_chromatic(ModifierPreference::Flat),
+            note("B")
+        );
+        assert_eq!(
+            note("D").prev_chromatic(ModifierPreference::Sharp),
+            note("C#")
+        );
+        assert_eq!(
+            note("Fb").prev_chromatic(ModifierPreference::Flat),
+            note("Eb")
+        );
+
+        for modifier_preference in [ModifierPreference::Sharp, ModifierPreference::Flat] {
+            let chromatic = AbstractNote::all_chromatic(modifier_preference);
+            for (i, step) in chromatic.iter().enumerate() {
+                let next = chromatic[(i + 1) % 12];
+                assert_eq!(step.next_chromatic(modifier_preference), next);
+                assert_eq!(next.prev_chromatic(modifier_preference), *step);
+            }
+        }
+    }
+
+    #[test]
+    fn subtracts_notes_into_intervals() {
+        let note = |name: &str| AbstractNote::try_from(name).unwrap();
+
+        assert_eq!(note("E") - note("C"), SimpleInterval::MajorThird);
+        assert_eq!(note("C") - note("E"), SimpleInterval::MinorSixth);
+        assert_eq!(note("G") - note("G"), SimpleInterval::PerfectUnison);
+
+        // The same pitch with a different letter is a diminished second up
+        // from C# to Db.
+        assert_eq!(note("Db") - note("C#"), SimpleInterval::DiminishedSecond);
+
+        for (lower, upper) in [("C", "F#"), ("Bb", "D"), ("E", "C"), ("F", "Cb")] {
+            let interval = note(upper) - note(lower);
+            assert_eq!(
+                interval,
+                note(lower).correctly_spelled_interval_to(note(upper))
+            );
+            assert_eq!(note(lower).spelled_add_interval(interval), note(upper));
+        }
+    }
 }
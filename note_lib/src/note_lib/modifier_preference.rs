@@ -1,4 +1,5 @@
 use super::NoteModifier;
+use crate::{Key, ScaleMode};
 
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub enum ModifierPreference {
@@ -14,3 +15,91 @@ impl From<NoteModifier> for ModifierPreference {
         }
     }
 }
+
+impl ModifierPreference {
+    /// Gets the modifier that the key signature of `key` uses. Sharp keys,
+    /// like G major, prefer sharps and flat keys, like F major, prefer flats.
+    /// Keys without any sharps or flats, like C major, prefer sharps.
+    ///
+    /// Modes share the key signature of their relative major, so D Dorian is
+    /// treated like C major and D Aeolian like F major.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ModifierPreference, ScaleMode};
+    ///
+    /// let bb_major = Key::new(AbstractNote::try_from("Bb").unwrap(), ScaleMode::Ionian);
+    /// assert_eq!(ModifierPreference::for_key(&bb_major), ModifierPreference::Flat);
+    /// ```
+    pub fn for_key(key: &Key) -> ModifierPreference {
+        // How far around the circle of fifths the relative major's tonic is
+        // from this mode's tonic.
+        let relative_major_offset = match key.mode() {
+            ScaleMode::Lydian => 1,
+            ScaleMode::Ionian | ScaleMode::PentatonicMajor => 0,
+            ScaleMode::Mixolydian | ScaleMode::LydianDominant => -1,
+            ScaleMode::Dorian => -2,
+            ScaleMode::Aeolian
+            | ScaleMode::HarmonicMinor
+            | ScaleMode::PentatonicMinor
+            | ScaleMode::Blues => -3,
+            ScaleMode::Phrygian => -4,
+            ScaleMode::Locrian => -5,
+        };
+
+        if key.root().cycle_of_fifths_position() + relative_major_offset < 0 {
+            ModifierPreference::Flat
+        } else {
+            ModifierPreference::Sharp
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::AbstractNote;
+
+    fn key(root: &str, mode: ScaleMode) -> Key {
+        Key::new(AbstractNote::try_from(root).unwrap(), mode)
+    }
+
+    #[test]
+    fn follows_key_signatures() {
+        for root in ["C", "G", "D", "A", "E", "B", "F#", "C#"] {
+            assert_eq!(
+                ModifierPreference::for_key(&key(root, ScaleMode::Ionian)),
+                ModifierPreference::Sharp,
+                "{root} major"
+            );
+        }
+
+        for root in ["F", "Bb", "Eb", "Ab", "Db", "Gb"] {
+            assert_eq!(
+                ModifierPreference::for_key(&key(root, ScaleMode::Ionian)),
+                ModifierPreference::Flat,
+                "{root} major"
+            );
+        }
+    }
+
+    #[test]
+    fn follows_relative_major_for_modes() {
+        assert_eq!(
+            ModifierPreference::for_key(&key("A", ScaleMode::Aeolian)),
+            ModifierPreference::Sharp
+        );
+        assert_eq!(
+            ModifierPreference::for_key(&key("D", ScaleMode::Aeolian)),
+            ModifierPreference::Flat
+        );
+        assert_eq!(
+            ModifierPreference::for_key(&key("G", ScaleMode::Dorian)),
+            ModifierPreference::Flat
+        );
+        assert_eq!(
+            ModifierPreference::for_key(&key("E", ScaleMode::Phrygian)),
+            ModifierPreference::Sharp
+        );
+    }
+}
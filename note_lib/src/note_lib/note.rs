@@ -1,33 +1,119 @@
-use super::{AbstractNote, ModifierPreference, NoteModifier, RawNote};
-use crate::{Chord, Hertz, Octave, Semitone, SimpleInterval, SimpleIntervalFromSemitones};
+use super::{AbstractNote, AbstractNoteParseError, ModifierPreference, NoteModifier, RawNote};
+use crate::{
+    Cents, Chord, CompoundInterval, Hertz, Key, Octave, OctaveRangeError, Scale, Semitone,
+    SimpleInterval, SimpleIntervalFromSemitones,
+};
 use std::{
     fmt::{Display, Formatter},
-    ops::Add,
+    ops::{Add, Sub},
     vec,
 };
 
 #[derive(PartialEq, Clone, Debug, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Note {
     abstract_note: AbstractNote,
     octave: Octave,
 }
 
 impl Note {
-    pub fn new(raw_note: RawNote, octave: i32, modifier: NoteModifier) -> Self {
+    /// Creates a note. Panics if the octave is outside the MIDI range, see
+    /// [`Octave`].
+    pub fn new(raw_note: RawNote, octave: impl Into<Octave>, modifier: NoteModifier) -> Self {
         Note {
-            octave,
+            octave: octave.into(),
             abstract_note: AbstractNote { raw_note, modifier },
         }
     }
 
     pub fn to_hertz(&self) -> Hertz {
-        self.abstract_note.raw_note.to_hertz() * 2.0f32.powi(self.octave)
+        let modifier_semitones = Semitone::from(self.abstract_note.modifier) as f32;
+
+        self.abstract_note.raw_note.to_hertz()
+            * 2.0f32.powi(self.octave.value())
+            * 2.0f32.powf(modifier_semitones / 12.0)
+    }
+
+    /// Gets the frequency `interval` above `reference` in just intonation,
+    /// using [`SimpleInterval::just_frequency_ratio`]. Unlike adding the
+    /// interval to `reference` and calling [`Note::to_hertz`], which is equal
+    /// tempered, the result is a pure ratio of the reference's frequency.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, SimpleInterval, C};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let just_g4 = Note::just_frequency(c4, SimpleInterval::PerfectFifth);
+    ///
+    /// // A pure 3:2 above C4, about 392.4 Hz. The equal tempered G4 is about 392.0 Hz.
+    /// assert_eq!(just_g4.value(), c4.to_hertz().value() * 1.5);
+    /// ```
+    pub fn just_frequency(reference: Note, interval: SimpleInterval) -> Hertz {
+        let (numerator, denominator) = interval.just_frequency_ratio();
+
+        reference.to_hertz() * (numerator as f32 / denominator as f32)
+    }
+
+    /// How far `target` is from this note's equal tempered pitch. A positive
+    /// deviation means `target` is sharp of this note.
+    ///
+    /// ```rust
+    /// use note_lib::{Cents, Hertz, Note, NoteModifier, RawNote};
+    ///
+    /// let a4 = Note::new(RawNote::A, 4, NoteModifier::Natural);
+    /// let deviation = a4.cents_deviation(Hertz::new(442.0).unwrap());
+    ///
+    /// assert!((deviation.value() - 7.85).abs() < 0.01);
+    /// assert!(!deviation.is_in_tune(Cents::new(5.0)));
+    /// ```
+    pub fn cents_deviation(&self, target: Hertz) -> Cents {
+        let ratio = target.value() as f64 / self.to_hertz().value() as f64;
+
+        Cents::new(1200.0 * ratio.log2())
     }
 
     pub fn octave(&self) -> Octave {
         self.octave
     }
 
+    /// Gets this note in another octave, keeping its spelling. The octave is
+    /// the octave of the written letter, so B#4 moved to octave 3 is B#3,
+    /// even though B#3 sounds the same as C4. Panics if the octave is outside
+    /// the MIDI range, like [`Note::new`].
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, G};
+    ///
+    /// let g2 = Note::new(G, 2, NoteModifier::Natural);
+    /// assert_eq!(g2.octave_equivalent(5), Note::new(G, 5, NoteModifier::Natural));
+    /// ```
+    pub fn octave_equivalent(&self, octave: impl Into<Octave>) -> Note {
+        self.abstract_note.to_note(octave)
+    }
+
+    /// Gets the octave equivalent of this note whose pitch is closest to
+    /// `target`, for placing a detected pitch in the right octave. Closeness is
+    /// measured in cents, so a note an octave too high counts as far off as
+    /// one an octave too low.
+    ///
+    /// ```rust
+    /// use note_lib::{Hertz, Note, NoteModifier, A};
+    ///
+    /// let a4 = Note::new(A, 4, NoteModifier::Natural);
+    /// let placed = a4.nearest_octave_for(Hertz::new(115.0).unwrap());
+    ///
+    /// assert_eq!(placed, Note::new(A, 2, NoteModifier::Natural));
+    /// ```
+    pub fn nearest_octave_for(&self, target: Hertz) -> Note {
+        (Octave::MIN..=Octave::MAX)
+            .map(|octave| self.octave_equivalent(octave))
+            .min_by(|a, b| {
+                let distance = |note: &Note| note.cents_deviation(target).value().abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap_or(*self)
+    }
+
     pub fn raw_note(&self) -> RawNote {
         self.abstract_note.raw_note
     }
@@ -60,51 +146,420 @@ impl Note {
             _ => AbstractNote::from_interval_from_c(interval, modifier_preference),
         };
 
-        abstract_note.at_octave(octave_overflow)
+        abstract_note.to_note(octave_overflow)
+    }
+
+    /// Creates the note `semitones` above C0, spelled with the accidentals of
+    /// `key` from [`ModifierPreference::for_key`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, Note, NoteModifier, ScaleMode, D};
+    ///
+    /// let f_major = Key::new(AbstractNote::try_from("F").unwrap(), ScaleMode::Ionian);
+    /// let note = Note::from_semitones_in_key(49, &f_major);
+    ///
+    /// assert_eq!(note, Note::new(D, 4, NoteModifier::Flat));
+    /// ```
+    pub fn from_semitones_in_key(semitones_from_low_c: Semitone, key: &Key) -> Note {
+        Note::from_semitones_from_c0(semitones_from_low_c, ModifierPreference::for_key(key))
     }
 
     pub fn to_semitones_from_c0(&self) -> Semitone {
-        let mut semitones_from_c = 0;
+        let semitones_from_c = self.abstract_note.raw_note.chromatic_index() as Semitone;
+
+        let semitones_before_modified = (self.octave.value() * 12) + semitones_from_c;
 
-        let mut current_note = self.abstract_note.raw_note;
-        let mut current_octave = 0;
+        semitones_before_modified + Semitone::from(self.abstract_note.modifier)
+    }
 
-        while current_octave < self.octave {
-            current_octave += 1;
+    /// Whether this note sounds between `lo` and `hi`, inclusive. Enharmonic
+    /// notes are compared by pitch, so B#3 is in a range starting at C4.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// assert!(Note::new(E, 4, NoteModifier::Natural).in_range(c4, g4));
+    /// assert!(!Note::new(E, 5, NoteModifier::Natural).in_range(c4, g4));
+    /// ```
+    pub fn in_range(&self, lo: Note, hi: Note) -> bool {
+        let semitones = self.to_semitones_from_c0();
+        lo.to_semitones_from_c0() <= semitones && semitones <= hi.to_semitones_from_c0()
+    }
+
+    /// Gets this note if it is between `lo` and `hi`, otherwise whichever of
+    /// the two it is past. The boundary note is returned as given, keeping its
+    /// spelling.
+    pub fn clamped_to_range(&self, lo: Note, hi: Note) -> Note {
+        let semitones = self.to_semitones_from_c0();
+        if semitones < lo.to_semitones_from_c0() {
+            lo
+        } else if semitones > hi.to_semitones_from_c0() {
+            hi
+        } else {
+            *self
         }
+    }
 
-        while current_note != RawNote::C {
-            match current_note {
-                RawNote::C => (),
-                RawNote::Incongruent(_) => unreachable!(),
-                _ => {
-                    let (prev_note, semitones_to_prev_note) = current_note.prev_note();
-                    current_note = prev_note;
-                    semitones_from_c += semitones_to_prev_note;
-                }
-            }
+    /// Gets the next note of `scale` that is strictly higher in pitch than this
+    /// note, moving into the next octave when stepping past the seventh degree.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, B, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let next = Note::new(B, 4, NoteModifier::Natural).next_note_diatonic(&c_major);
+    ///
+    /// assert_eq!(next, Note::new(C, 5, NoteModifier::Natural));
+    /// ```
+    pub fn next_note_diatonic(&self, scale: &Scale) -> Note {
+        let (next, semitones) = self.next_diatonic_step(scale);
+        Note::place_at_semitones(next, semitones)
+    }
+
+    /// Gets the next note of `scale` above this note, like
+    /// [`Note::next_note_diatonic`]. Returns an error if the next note would be
+    /// outside the range of [`Octave`].
+    pub fn try_next_note_diatonic(&self, scale: &Scale) -> Result<Note, NoteRangeError> {
+        let (next, semitones) = self.next_diatonic_step(scale);
+        Note::try_place_at_semitones(next, semitones)
+    }
+
+    /// Gets the next note of `scale` and how many semitones above C0 it sounds.
+    fn next_diatonic_step(&self, scale: &Scale) -> (AbstractNote, Semitone) {
+        let next = self
+            .abstract_note
+            .next_note_diatonic(scale.root(), scale.mode());
+        let semitones_up = (next.chromatic_index() as Semitone
+            - self.abstract_note.chromatic_index() as Semitone
+            - 1)
+        .rem_euclid(12)
+            + 1;
+
+        (next, self.to_semitones_from_c0() + semitones_up)
+    }
+
+    /// Gets the previous note of `scale` that is strictly lower in pitch than
+    /// this note, moving into the previous octave when stepping below the root.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, B, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let prev = Note::new(C, 4, NoteModifier::Natural).prev_note_diatonic(&c_major);
+    ///
+    /// assert_eq!(prev, Note::new(B, 3, NoteModifier::Natural));
+    /// ```
+    pub fn prev_note_diatonic(&self, scale: &Scale) -> Note {
+        let (prev, semitones) = self.prev_diatonic_step(scale);
+        Note::place_at_semitones(prev, semitones)
+    }
+
+    /// Gets the previous note of `scale` below this note, like
+    /// [`Note::prev_note_diatonic`]. Returns an error if the previous note
+    /// would be outside the range of [`Octave`].
+    pub fn try_prev_note_diatonic(&self, scale: &Scale) -> Result<Note, NoteRangeError> {
+        let (prev, semitones) = self.prev_diatonic_step(scale);
+        Note::try_place_at_semitones(prev, semitones)
+    }
+
+    /// Gets the previous note of `scale` and how many semitones above C0 it
+    /// sounds.
+    fn prev_diatonic_step(&self, scale: &Scale) -> (AbstractNote, Semitone) {
+        let prev = self
+            .abstract_note
+            .prev_note_diatonic(scale.root(), scale.mode());
+        let semitones_down = (self.abstract_note.chromatic_index() as Semitone
+            - prev.chromatic_index() as Semitone
+            - 1)
+        .rem_euclid(12)
+            + 1;
+
+        (prev, self.to_semitones_from_c0() - semitones_down)
+    }
+
+    /// Displays this note spelled the way `key` spells its pitch class, using
+    /// [`Scale::spell_note`]. The octave follows the pitch, so B#3 in C major
+    /// is displayed as C4. Notes outside the key keep their own spelling.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, Note, NoteModifier, ScaleMode, A};
+    ///
+    /// let f_major = Key::new(AbstractNote::try_from("F").unwrap(), ScaleMode::Ionian);
+    /// let a_sharp = Note::new(A, 4, NoteModifier::Sharp);
+    ///
+    /// assert_eq!(a_sharp.display_in_key(&f_major), "Bb4");
+    /// ```
+    pub fn display_in_key(&self, key: &Key) -> String {
+        let spelled = key.scale().spell_note(self.abstract_note);
+        Note::place_at_semitones(spelled, self.to_semitones_from_c0()).to_string()
+    }
+
+    /// Writes this note in scientific pitch notation, like "C#4". This is the
+    /// same as [`Note::to_structural_notation`]: the octave number belongs to
+    /// the letter name, so B#4 stays "B#4" even though it sounds like C5. Use
+    /// [`Note::to_enharmonic_notation`] to write notes by how they sound.
+    pub fn to_standard_notation_string(&self) -> String {
+        self.to_structural_notation()
+    }
+
+    /// Writes this note as it's spelled, like "B#4" or "Cb4".
+    pub fn to_structural_notation(&self) -> String {
+        self.to_string()
+    }
+
+    /// Writes this note so its octave number matches how it sounds. Notes
+    /// that sound like a natural note are written as that note, so B#4 is
+    /// "C5", Cb4 is "B3", E#4 is "F4" and Fb4 is "E4". Other notes keep the
+    /// direction of their modifier but use a single sharp or flat, so Bx4 is
+    /// "C#5".
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, B, C};
+    ///
+    /// assert_eq!(Note::new(B, 4, NoteModifier::Sharp).to_enharmonic_notation(), "C5");
+    /// assert_eq!(Note::new(C, 4, NoteModifier::Flat).to_enharmonic_notation(), "B3");
+    /// ```
+    pub fn to_enharmonic_notation(&self) -> String {
+        Note::from_semitones_from_c0(self.to_semitones_from_c0(), self.modifier().into())
+            .to_string()
+    }
+
+    /// Places `note` in whichever octave makes it sound `semitones` above C0.
+    pub(crate) fn place_at_semitones(note: AbstractNote, semitones: Semitone) -> Note {
+        let octave = (semitones - note.to_note(0).to_semitones_from_c0()).div_euclid(12);
+        note.to_note(octave)
+    }
+
+    /// Places `note` like [`Note::place_at_semitones`], or returns an error if
+    /// that octave is outside the range of [`Octave`].
+    pub(crate) fn try_place_at_semitones(
+        note: AbstractNote,
+        semitones: Semitone,
+    ) -> Result<Note, NoteRangeError> {
+        match Octave::new((semitones - note.to_note(0).to_semitones_from_c0()).div_euclid(12)) {
+            Ok(octave) => Ok(note.to_note(octave)),
+            Err(OctaveRangeError::TooLow(_)) => Err(NoteRangeError::TooLow(semitones)),
+            Err(OctaveRangeError::TooHigh(_)) => Err(NoteRangeError::TooHigh(semitones)),
         }
+    }
 
-        let semitones_before_modified = (current_octave * 12) + semitones_from_c;
-        
+    /// Gets the MIDI note number of this note, where C4 is 60. Returns `None`
+    /// if the note is outside MIDI's range of 0 (C-1) to 127 (G9).
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, A, B, C, G};
+    ///
+    /// assert_eq!(Note::new(C, 4, NoteModifier::Natural).midi_number(), Some(60));
+    /// assert_eq!(Note::new(A, 4, NoteModifier::Natural).midi_number(), Some(69));
+    /// assert_eq!(Note::new(C, -1, NoteModifier::Natural).midi_number(), Some(0));
+    /// assert_eq!(Note::new(G, 9, NoteModifier::Sharp).midi_number(), None);
+    /// assert_eq!(Note::new(B, 9, NoteModifier::Natural).midi_number(), None);
+    /// ```
+    pub fn midi_number(&self) -> Option<u8> {
+        u8::try_from(self.to_semitones_from_c0() + 12)
+            .ok()
+            .filter(|midi_number| *midi_number <= 127)
+    }
 
-        semitones_before_modified + Semitone::from(self.abstract_note.modifier)
+    /// Creates a note from a MIDI note number, where 60 is C4. Black keys are
+    /// spelled using `modifier_preference`. Returns `None` for numbers above 127.
+    ///
+    /// ```rust
+    /// use note_lib::{ModifierPreference, Note, NoteModifier, C, E};
+    ///
+    /// let note = Note::from_midi_number(61, ModifierPreference::Sharp);
+    /// assert_eq!(note, Some(Note::new(C, 4, NoteModifier::Sharp)));
+    ///
+    /// let note = Note::from_midi_number(63, ModifierPreference::Flat);
+    /// assert_eq!(note, Some(Note::new(E, 4, NoteModifier::Flat)));
+    ///
+    /// assert_eq!(Note::from_midi_number(128, ModifierPreference::Sharp), None);
+    /// ```
+    pub fn from_midi_number(
+        midi_number: u8,
+        modifier_preference: ModifierPreference,
+    ) -> Option<Note> {
+        if midi_number > 127 {
+            return None;
+        }
+
+        Some(Note::from_semitones_from_c0(
+            midi_number as Semitone - 12,
+            modifier_preference,
+        ))
     }
 
-    pub fn add_semitones(&self, semitones: Semitone) -> Note {
+    /// Moves this note up (or down, for negative semitones) by `semitones`.
+    /// The new note is spelled with the same kind of modifier as this one, so
+    /// flats stay flats. Returns an error if the new note would be outside the
+    /// range of [`Octave`].
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, NoteRangeError, A, B, C};
+    ///
+    /// let b_flat3 = Note::new(B, 3, NoteModifier::Flat);
+    /// assert_eq!(b_flat3.try_add_semitones(-1), Ok(Note::new(A, 3, NoteModifier::Natural)));
+    ///
+    /// let c_lowest = Note::new(C, -1, NoteModifier::Natural);
+    /// assert_eq!(c_lowest.try_add_semitones(-1), Err(NoteRangeError::TooLow(-13)));
+    /// ```
+    pub fn try_add_semitones(&self, semitones: Semitone) -> Result<Note, NoteRangeError> {
         let new_semitones = self.to_semitones_from_c0() + semitones;
 
-        if new_semitones < 0 {
-            panic!("Cannot add semitones to a note that would result in a negative semitone value from C0.")
-        };
+        // Notes made from semitones are never spelled across an octave, like
+        // B# or Cb, so the octave is just the whole octaves above C0.
+        match Octave::new(new_semitones.div_euclid(12)) {
+            Ok(_) => Ok(self.add_semitones_unchecked(semitones)),
+            Err(OctaveRangeError::TooLow(_)) => Err(NoteRangeError::TooLow(new_semitones)),
+            Err(OctaveRangeError::TooHigh(_)) => Err(NoteRangeError::TooHigh(new_semitones)),
+        }
+    }
 
-        Note::from_semitones_from_c0(new_semitones, self.abstract_note.modifier.into())
+    /// Moves this note up by `interval`, like [`Note::try_add_semitones`].
+    pub fn try_add_interval(&self, interval: SimpleInterval) -> Result<Note, NoteRangeError> {
+        self.try_add_semitones(interval.semitones())
+    }
+
+    /// Like [`Note::try_add_semitones`], but panics if the new note would be
+    /// outside the range of [`Octave`].
+    pub fn add_semitones_unchecked(&self, semitones: Semitone) -> Note {
+        Note::from_semitones_from_c0(
+            self.to_semitones_from_c0() + semitones,
+            self.abstract_note.modifier.into(),
+        )
+    }
+
+    #[deprecated(
+        note = "panics if the note leaves the octave range, use `try_add_semitones` instead"
+    )]
+    pub fn add_semitones(&self, semitones: Semitone) -> Note {
+        self.try_add_semitones(semitones).unwrap()
+    }
+
+    /// Builds a chord of this note followed by `others`, in that order. This
+    /// saves chaining `+` when the notes are already in a slice.
+    ///
+    /// ```rust
+    /// use note_lib::{Note, NoteModifier, C, E, G};
+    ///
+    /// let c4 = Note::new(C, 4, NoteModifier::Natural);
+    /// let e4 = Note::new(E, 4, NoteModifier::Natural);
+    /// let g4 = Note::new(G, 4, NoteModifier::Natural);
+    ///
+    /// assert_eq!(c4.chord_with(&[e4, g4]), c4 + e4 + g4);
+    /// ```
+    pub fn chord_with(&self, others: &[Note]) -> Chord {
+        Chord::new(std::iter::once(self).chain(others).copied().collect())
+    }
+}
+
+/// A note that would be outside the range of [`Octave`]. Each variant holds
+/// the semitones from C0 of the note that couldn't be made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteRangeError {
+    /// The note would be below C-1.
+    TooLow(Semitone),
+    /// The note would be above B9.
+    TooHigh(Semitone),
+}
+
+impl Display for NoteRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoteRangeError::TooLow(semitones) => write!(
+                f,
+                "a note {} semitones from C0 is below the lowest octave",
+                semitones
+            ),
+            NoteRangeError::TooHigh(semitones) => write!(
+                f,
+                "a note {} semitones from C0 is above the highest octave",
+                semitones
+            ),
+        }
     }
 }
 
+/// A MIDI note number outside MIDI's range of 0 (C-1) to 127 (G9). Holds the
+/// number that was out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MidiRangeError(pub Semitone);
+
+impl Display for MidiRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is outside the MIDI note range of 0 to 127", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoteParseError {
+    /// The note name wasn't followed by an octave number.
+    MissingOctave,
+    /// The input didn't start with a note letter from A to G.
+    InvalidRoot,
+    /// The characters between the letter and the octave weren't a modifier.
+    InvalidModifier,
+    /// The octave wasn't a number, or was outside [`Octave`]'s range.
+    InvalidOctave,
+}
+
+/// Parses a note in scientific pitch notation: a letter, an optional
+/// modifier, and the octave, like "E4", "Bb3", "F##5" or "C-1". This is the
+/// format notes are displayed in, so displayed notes parse back to the same
+/// note.
+///
+/// ```rust
+/// use note_lib::{Note, NoteModifier, C};
+///
+/// assert_eq!(Note::try_from("C#4"), Ok(Note::new(C, 4, NoteModifier::Sharp)));
+/// ```
+impl TryFrom<&str> for Note {
+    type Error = NoteParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if !value.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(NoteParseError::InvalidRoot);
+        }
+
+        let octave_start = value
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| c.is_ascii_digit() || *c == '-')
+            .map(|(index, _)| index)
+            .ok_or(NoteParseError::MissingOctave)?;
+        let (name, octave) = value.split_at(octave_start);
+
+        let abstract_note = AbstractNote::try_from(name).map_err(|error| match error {
+            AbstractNoteParseError::EmptyInput | AbstractNoteParseError::InvalidNote => {
+                NoteParseError::InvalidRoot
+            }
+            AbstractNoteParseError::InvalidModifier | AbstractNoteParseError::InputTooLong => {
+                NoteParseError::InvalidModifier
+            }
+        })?;
+        let octave = octave
+            .parse::<i32>()
+            .ok()
+            .and_then(|octave| Octave::new(octave).ok())
+            .ok_or(NoteParseError::InvalidOctave)?;
+
+        Ok(abstract_note.to_note(octave))
+    }
+}
+
+/// Displays the note name and octave, like `C#4`. The alternate form (`{:#}`)
+/// uses the musical symbol for the modifier, like `C♯4`.
 impl Display for Note {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        Formatter::write_fmt(f, format_args!("{}{}", self.abstract_note, self.octave))
+        if f.alternate() {
+            Formatter::write_fmt(f, format_args!("{:#}{}", self.abstract_note, self.octave))
+        } else {
+            Formatter::write_fmt(f, format_args!("{}{}", self.abstract_note, self.octave))
+        }
     }
 }
 
@@ -116,10 +571,42 @@ impl Add for Note {
     }
 }
 
+/// Moves the note up by the semitones of a compound interval, spelling any
+/// black key the way this note leans, so naturals and sharps give sharps and
+/// flats give flats. Like [`Note::add_semitones_unchecked`], this panics if the
+/// new note would be outside the range of [`Octave`].
+///
+/// ```rust
+/// use note_lib::{CompoundInterval, Note, NoteModifier, C, D};
+///
+/// let c4 = Note::new(C, 4, NoteModifier::Natural);
+/// assert_eq!(c4 + CompoundInterval::MajorNinth, Note::new(D, 5, NoteModifier::Natural));
+/// ```
+impl Add<CompoundInterval> for Note {
+    type Output = Note;
+
+    fn add(self, rhs: CompoundInterval) -> Self::Output {
+        self.add_semitones_unchecked(rhs.semitones())
+    }
+}
+
+/// Moves the note down by the semitones of a compound interval, spelled and
+/// range checked the same way as adding one.
+impl Sub<CompoundInterval> for Note {
+    type Output = Note;
+
+    fn sub(self, rhs: CompoundInterval) -> Self::Output {
+        self.add_semitones_unchecked(-rhs.semitones())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
+    use strum::IntoEnumIterator;
+
     use super::*;
+    use crate::{IntervalQuality, ScaleMode};
 
     #[test]
     fn should_create() {
@@ -129,6 +616,18 @@ mod tests {
         assert_eq!(note.modifier(), NoteModifier::Natural);
     }
 
+    #[test]
+    #[should_panic]
+    fn should_reject_octave_outside_midi_range() {
+        Note::new(RawNote::C, 500, NoteModifier::Natural);
+    }
+
+    #[test]
+    fn should_get_semitones_below_c0() {
+        let note = Note::new(RawNote::B, -1, NoteModifier::Natural);
+        assert_eq!(note.to_semitones_from_c0(), -1);
+    }
+
     #[test]
     fn should_create_from_semitones() {
         // 12 up from C0 is C1.
@@ -174,4 +673,313 @@ mod tests {
         let note = Note::new(RawNote::C, 4, NoteModifier::Flat);
         assert_eq!(note.to_semitones_from_c0(), 47);
     }
+
+    #[test]
+    fn should_get_hertz() {
+        let note = Note::new(RawNote::A, 4, NoteModifier::Natural);
+        assert_eq!(note.to_hertz(), Hertz::new(440.0).unwrap());
+
+        let note = Note::new(RawNote::C, 4, NoteModifier::Natural);
+        assert!((note.to_hertz().value() - 261.6).abs() < 0.1);
+
+        let note = Note::new(RawNote::A, 4, NoteModifier::Flat);
+        assert!((note.to_hertz().value() - 415.3).abs() < 0.1);
+    }
+
+    #[test]
+    fn should_get_cents_deviation() {
+        let note = Note::new(RawNote::A, 4, NoteModifier::Natural);
+        assert!(note.cents_deviation(note.to_hertz()).value().abs() < 0.01);
+
+        let sharp = Note::new(RawNote::A, 4, NoteModifier::Sharp);
+        let deviation = note.cents_deviation(sharp.to_hertz());
+        assert!((deviation.value() - 100.0).abs() < 0.01);
+        assert!(deviation.is_in_tune(Cents::new(100.01)));
+    }
+
+    #[test]
+    fn should_step_diatonically_across_octaves() {
+        let c_major = Scale::new(AbstractNote::from(RawNote::C), ScaleMode::Ionian);
+
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+        let b3 = Note::new(RawNote::B, 3, NoteModifier::Natural);
+        assert_eq!(c4.prev_note_diatonic(&c_major), b3);
+        assert_eq!(b3.next_note_diatonic(&c_major), c4);
+
+        let d4 = Note::new(RawNote::D, 4, NoteModifier::Natural);
+        assert_eq!(c4.next_note_diatonic(&c_major), d4);
+        assert_eq!(d4.prev_note_diatonic(&c_major), c4);
+
+        // B#3 sounds the same as C4, so the octave number only changes when
+        // stepping from B# up to C#.
+        let c_sharp_major = Scale::new(AbstractNote::try_from("C#").unwrap(), ScaleMode::Ionian);
+        let a_sharp3 = Note::new(RawNote::A, 3, NoteModifier::Sharp);
+        let b_sharp3 = Note::new(RawNote::B, 3, NoteModifier::Sharp);
+        let c_sharp4 = Note::new(RawNote::C, 4, NoteModifier::Sharp);
+        assert_eq!(a_sharp3.next_note_diatonic(&c_sharp_major), b_sharp3);
+        assert_eq!(b_sharp3.next_note_diatonic(&c_sharp_major), c_sharp4);
+        assert_eq!(c_sharp4.prev_note_diatonic(&c_sharp_major), b_sharp3);
+    }
+
+    #[test]
+    fn should_round_trip_midi_numbers() {
+        for midi_number in 0..=127 {
+            for preference in [ModifierPreference::Sharp, ModifierPreference::Flat] {
+                let note = Note::from_midi_number(midi_number, preference).unwrap();
+                assert_eq!(note.midi_number(), Some(midi_number));
+            }
+        }
+    }
+
+    #[test]
+    fn should_check_range() {
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+        let g4 = Note::new(RawNote::G, 4, NoteModifier::Natural);
+        let e4 = Note::new(RawNote::E, 4, NoteModifier::Natural);
+        let b3 = Note::new(RawNote::B, 3, NoteModifier::Natural);
+        let a_flat4 = Note::new(RawNote::A, 4, NoteModifier::Flat);
+
+        assert!(c4.in_range(c4, g4));
+        assert!(g4.in_range(c4, g4));
+        assert!(e4.in_range(c4, g4));
+        assert!(Note::new(RawNote::B, 3, NoteModifier::Sharp).in_range(c4, g4));
+        assert!(!b3.in_range(c4, g4));
+        assert!(!a_flat4.in_range(c4, g4));
+
+        assert_eq!(b3.clamped_to_range(c4, g4), c4);
+        assert_eq!(a_flat4.clamped_to_range(c4, g4), g4);
+        assert_eq!(e4.clamped_to_range(c4, g4), e4);
+    }
+
+    #[test]
+    fn should_add_semitones_within_range() {
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+        assert_eq!(
+            c4.try_add_semitones(4),
+            Ok(Note::new(RawNote::E, 4, NoteModifier::Natural))
+        );
+        assert_eq!(
+            c4.try_add_interval(SimpleInterval::PerfectFifth),
+            Ok(Note::new(RawNote::G, 4, NoteModifier::Natural))
+        );
+        assert_eq!(
+            c4.try_add_semitones(-60),
+            Ok(Note::new(RawNote::C, -1, NoteModifier::Natural))
+        );
+        assert_eq!(c4.try_add_semitones(-61), Err(NoteRangeError::TooLow(-13)));
+        assert_eq!(
+            c4.try_add_semitones(71),
+            Ok(Note::new(RawNote::B, 9, NoteModifier::Natural))
+        );
+        assert_eq!(c4.try_add_semitones(72), Err(NoteRangeError::TooHigh(120)));
+    }
+
+    #[test]
+    fn displays_notes_in_keys() {
+        let key = |root: &str| Key::new(AbstractNote::try_from(root).unwrap(), ScaleMode::Ionian);
+
+        // Notes in the key use the key's spelling.
+        assert_eq!(
+            Note::new(RawNote::A, 4, NoteModifier::Sharp).display_in_key(&key("F")),
+            "Bb4"
+        );
+        assert_eq!(
+            Note::new(RawNote::G, 2, NoteModifier::Flat).display_in_key(&key("D")),
+            "F#2"
+        );
+        assert_eq!(
+            Note::new(RawNote::C, 4, NoteModifier::Natural).display_in_key(&key("C")),
+            "C4"
+        );
+
+        // Notes outside the key are left alone.
+        assert_eq!(
+            Note::new(RawNote::C, 4, NoteModifier::Sharp).display_in_key(&key("F")),
+            "C#4"
+        );
+        assert_eq!(
+            Note::new(RawNote::E, 5, NoteModifier::Flat).display_in_key(&key("G")),
+            "Eb5"
+        );
+
+        // Respelling across B and C moves the octave with the pitch.
+        assert_eq!(
+            Note::new(RawNote::B, 3, NoteModifier::Sharp).display_in_key(&key("C")),
+            "C4"
+        );
+        assert_eq!(
+            Note::new(RawNote::C, 4, NoteModifier::Flat).display_in_key(&key("C")),
+            "B3"
+        );
+        assert_eq!(
+            Note::new(RawNote::B, 3, NoteModifier::Natural).display_in_key(&key("Gb")),
+            "Cb4"
+        );
+    }
+
+    #[test]
+    fn writes_structural_and_enharmonic_notation() {
+        let cases = [
+            (Note::new(RawNote::B, 4, NoteModifier::Sharp), "B#4", "C5"),
+            (Note::new(RawNote::C, 4, NoteModifier::Flat), "Cb4", "B3"),
+            (Note::new(RawNote::E, 4, NoteModifier::Sharp), "E#4", "F4"),
+            (Note::new(RawNote::F, 4, NoteModifier::Flat), "Fb4", "E4"),
+            (
+                Note::new(RawNote::B, 4, NoteModifier::DoubleSharp),
+                "B##4",
+                "C#5",
+            ),
+            (
+                Note::new(RawNote::D, 3, NoteModifier::DoubleFlat),
+                "Dbb3",
+                "C3",
+            ),
+            (Note::new(RawNote::C, 4, NoteModifier::Sharp), "C#4", "C#4"),
+            (Note::new(RawNote::G, 2, NoteModifier::Flat), "Gb2", "Gb2"),
+        ];
+
+        for (note, structural, enharmonic) in cases {
+            assert_eq!(note.to_structural_notation(), structural);
+            assert_eq!(note.to_standard_notation_string(), structural);
+            assert_eq!(note.to_enharmonic_notation(), enharmonic);
+        }
+    }
+
+    #[test]
+    fn parses_notes() {
+        for raw_note in AbstractNote::all_natural().map(|note| note.raw_note) {
+            for modifier in NoteModifier::iter() {
+                for octave in 0..=8 {
+                    let note = Note::new(raw_note, octave, modifier);
+                    assert_eq!(Note::try_from(note.to_string().as_str()), Ok(note));
+                }
+            }
+        }
+
+        assert_eq!(
+            Note::try_from(" Fx5 "),
+            Ok(Note::new(RawNote::F, 5, NoteModifier::DoubleSharp))
+        );
+        assert_eq!(
+            Note::try_from("Cb-1"),
+            Ok(Note::new(RawNote::C, -1, NoteModifier::Flat))
+        );
+
+        assert_eq!(Note::try_from("C#"), Err(NoteParseError::MissingOctave));
+        assert_eq!(Note::try_from(""), Err(NoteParseError::InvalidRoot));
+        assert_eq!(Note::try_from("H4"), Err(NoteParseError::InvalidRoot));
+        assert_eq!(Note::try_from("4"), Err(NoteParseError::InvalidRoot));
+        assert_eq!(Note::try_from("C?4"), Err(NoteParseError::InvalidModifier));
+        assert_eq!(Note::try_from("C#4b"), Err(NoteParseError::InvalidOctave));
+        assert_eq!(Note::try_from("C10"), Err(NoteParseError::InvalidOctave));
+    }
+
+    #[test]
+    fn moves_notes_between_octaves() {
+        let b_sharp4 = Note::new(RawNote::B, 4, NoteModifier::Sharp);
+        assert_eq!(
+            b_sharp4.octave_equivalent(3),
+            Note::new(RawNote::B, 3, NoteModifier::Sharp)
+        );
+
+        let g2 = Note::new(RawNote::G, 2, NoteModifier::Natural);
+        let hertz = |value| Hertz::new(value).unwrap();
+        // G4 is about 392 Hz.
+        assert_eq!(
+            g2.nearest_octave_for(hertz(400.0)),
+            Note::new(RawNote::G, 4, NoteModifier::Natural)
+        );
+        // Far above G9 and below G-1, the nearest octaves are the extremes.
+        assert_eq!(g2.nearest_octave_for(hertz(20000.0)).octave().value(), 9);
+        assert_eq!(g2.nearest_octave_for(hertz(1.0)).octave().value(), -1);
+    }
+
+    #[test]
+    fn tunes_intervals_justly() {
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+
+        for interval in SimpleInterval::iter() {
+            let just = Note::just_frequency(c4, interval);
+            let equal_tempered = c4.try_add_interval(interval).unwrap();
+            let deviation = equal_tempered.cents_deviation(just).value().abs();
+
+            // The plain major, minor and perfect intervals are all within 20
+            // cents of equal temperament. The rarer augmented and diminished
+            // ones can be more than 40 cents off.
+            let common = matches!(
+                interval.quality(),
+                IntervalQuality::Perfect | IntervalQuality::Major | IntervalQuality::Minor
+            );
+            let max_deviation = if common { 20.0 } else { 50.0 };
+            assert!(
+                deviation < max_deviation,
+                "{:?} is {} cents off",
+                interval,
+                deviation
+            );
+        }
+
+        assert_eq!(
+            Note::just_frequency(c4, SimpleInterval::PerfectOctave).value(),
+            c4.to_hertz().value() * 2.0
+        );
+    }
+
+    #[test]
+    fn builds_chords_from_notes() {
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+        let e4 = Note::new(RawNote::E, 4, NoteModifier::Natural);
+        let g4 = Note::new(RawNote::G, 4, NoteModifier::Natural);
+
+        let third = c4 + e4;
+        assert_eq!(third.notes(), &[c4, e4]);
+
+        let triad = third + g4;
+        assert_eq!(triad.notes(), &[c4, e4, g4]);
+
+        assert_eq!(c4.chord_with(&[e4, g4]), triad);
+        assert_eq!(c4.chord_with(&[e4, g4]).notes(), &[c4, e4, g4]);
+        assert_eq!(c4.chord_with(&[]).notes(), &[c4]);
+    }
+
+    #[test]
+    fn transposes_by_compound_intervals() {
+        let c4 = Note::new(RawNote::C, 4, NoteModifier::Natural);
+
+        assert_eq!(
+            c4 + CompoundInterval::MinorNinth,
+            Note::new(RawNote::C, 5, NoteModifier::Sharp)
+        );
+        assert_eq!(
+            c4 + CompoundInterval::PerfectTwelfth,
+            Note::new(RawNote::G, 5, NoteModifier::Natural)
+        );
+        assert_eq!(
+            c4 + CompoundInterval::PerfectFifteenth,
+            Note::new(RawNote::C, 6, NoteModifier::Natural)
+        );
+
+        // Flat notes keep flat spellings.
+        let eb4 = Note::new(RawNote::E, 4, NoteModifier::Flat);
+        assert_eq!(
+            eb4 + CompoundInterval::MinorTenth,
+            Note::new(RawNote::G, 5, NoteModifier::Flat)
+        );
+
+        // Two octaves and a minor third.
+        let other = CompoundInterval::from_semitones(27);
+        assert!(matches!(other, CompoundInterval::Other(_)));
+        assert_eq!(
+            c4 + other.clone(),
+            Note::new(RawNote::D, 6, NoteModifier::Sharp)
+        );
+
+        let d5 = Note::new(RawNote::D, 5, NoteModifier::Natural);
+        assert_eq!(d5 - CompoundInterval::MajorNinth, c4);
+        assert_eq!((c4 + other.clone()) - other, c4);
+        assert_eq!(
+            (c4 + CompoundInterval::PerfectTwelfth) - CompoundInterval::PerfectTwelfth,
+            c4
+        );
+    }
 }
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
+use crate::Semitone;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, strum_macros::EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NoteModifier {
     DoubleFlat,
     Flat,
@@ -11,16 +14,121 @@ pub enum NoteModifier {
     // TODO: How do we handle microtonal hoopla?
 }
 
+impl NoteModifier {
+    /// Gets how many semitones this modifier raises (or lowers, if negative) a
+    /// note by. This is the same as converting the modifier into a [`Semitone`].
+    ///
+    /// ```rust
+    /// use note_lib::NoteModifier;
+    ///
+    /// assert_eq!(NoteModifier::DoubleFlat.semitones(), -2);
+    /// assert_eq!(NoteModifier::Sharp.semitones(), 1);
+    /// ```
+    pub fn semitones(&self) -> Semitone {
+        Semitone::from(*self)
+    }
+
+    /// Gets the modifier that alters a note by the given amount of semitones,
+    /// if one exists. This is the inverse of converting a modifier into a
+    /// [`Semitone`].
+    ///
+    /// ```rust
+    /// use note_lib::NoteModifier;
+    ///
+    /// assert_eq!(NoteModifier::from_semitones(-1), Some(NoteModifier::Flat));
+    /// assert_eq!(NoteModifier::from_semitones(2), Some(NoteModifier::DoubleSharp));
+    /// assert_eq!(NoteModifier::from_semitones(3), None);
+    /// ```
+    pub fn from_semitones(semitones: Semitone) -> Option<NoteModifier> {
+        match semitones {
+            -2 => Some(NoteModifier::DoubleFlat),
+            -1 => Some(NoteModifier::Flat),
+            0 => Some(NoteModifier::Natural),
+            1 => Some(NoteModifier::Sharp),
+            2 => Some(NoteModifier::DoubleSharp),
+            _ => None,
+        }
+    }
+
+    /// Gets the musical symbol for this modifier, like `♯`. Naturals are
+    /// empty, since they aren't written after a note name.
+    ///
+    /// ```rust
+    /// use note_lib::NoteModifier;
+    ///
+    /// assert_eq!(NoteModifier::Flat.to_unicode(), "♭");
+    /// assert_eq!(NoteModifier::Natural.to_unicode(), "");
+    /// ```
+    pub fn to_unicode(&self) -> &'static str {
+        match self {
+            NoteModifier::DoubleFlat => "𝄫",
+            NoteModifier::Flat => "♭",
+            NoteModifier::Natural => "",
+            NoteModifier::Sharp => "♯",
+            NoteModifier::DoubleSharp => "𝄪",
+        }
+    }
+}
+
+/// Displays the modifier as it's written after a note name, like `#`. The
+/// alternate form (`{:#}`) displays the modifier's name instead, like `Sharp`.
 impl Display for NoteModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let modifier = match self {
-            NoteModifier::Sharp => "#",
-            NoteModifier::Flat => "b",
-            NoteModifier::Natural => "",
-            NoteModifier::DoubleSharp => "##",
-            NoteModifier::DoubleFlat => "bb",
+        let modifier = if !f.alternate() {
+            match self {
+                NoteModifier::Sharp => "#",
+                NoteModifier::Flat => "b",
+                NoteModifier::Natural => "",
+                NoteModifier::DoubleSharp => "##",
+                NoteModifier::DoubleFlat => "bb",
+            }
+        } else {
+            match self {
+                NoteModifier::Sharp => "Sharp",
+                NoteModifier::Flat => "Flat",
+                NoteModifier::Natural => "Natural",
+                NoteModifier::DoubleSharp => "DoubleSharp",
+                NoteModifier::DoubleFlat => "DoubleFlat",
+            }
         };
 
         write!(f, "{}", modifier)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn iterates_in_ascending_semitone_order() {
+        let semitones: Vec<Semitone> = NoteModifier::iter().map(|m| m.semitones()).collect();
+        assert_eq!(semitones, vec![-2, -1, 0, 1, 2]);
+
+        for modifier in NoteModifier::iter() {
+            assert_eq!(modifier.semitones(), Semitone::from(modifier));
+            assert_eq!(
+                NoteModifier::from_semitones(modifier.semitones()),
+                Some(modifier)
+            );
+        }
+    }
+
+    #[test]
+    fn displays_symbols_and_names() {
+        let symbols: Vec<String> = NoteModifier::iter().map(|m| format!("{}", m)).collect();
+        assert_eq!(symbols, vec!["bb", "b", "", "#", "##"]);
+
+        let names: Vec<String> = NoteModifier::iter().map(|m| format!("{:#}", m)).collect();
+        assert_eq!(
+            names,
+            vec!["DoubleFlat", "Flat", "Natural", "Sharp", "DoubleSharp"]
+        );
+
+        let unicode: Vec<&str> = NoteModifier::iter().map(|m| m.to_unicode()).collect();
+        assert_eq!(unicode, vec!["𝄫", "♭", "", "♯", "𝄪"]);
+    }
+}
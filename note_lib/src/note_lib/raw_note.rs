@@ -6,6 +6,7 @@ use super::{AbstractNote, NoteModifier};
 use crate::{Hertz, Semitone};
 
 #[derive(PartialEq, Clone, Copy, Debug, Default, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RawNote {
     /// A note that does not fit on the largely used 12-tone scale.
     Incongruent(Hertz),
@@ -46,19 +47,43 @@ impl RawNote {
         }
     }
 
+    /// Gets the semitones from C up to this natural note, so C is 0 and B is 11.
+    ///
+    /// Panics for [`RawNote::Incongruent`], which isn't on the 12-tone scale.
+    ///
+    /// ```rust
+    /// use note_lib::RawNote;
+    ///
+    /// assert_eq!(RawNote::C.chromatic_index(), 0);
+    /// assert_eq!(RawNote::F.chromatic_index(), 5);
+    /// assert_eq!(RawNote::B.chromatic_index(), 11);
+    /// ```
+    pub fn chromatic_index(&self) -> u8 {
+        match self {
+            RawNote::C => 0,
+            RawNote::D => 2,
+            RawNote::E => 4,
+            RawNote::F => 5,
+            RawNote::G => 7,
+            RawNote::A => 9,
+            RawNote::B => 11,
+            RawNote::Incongruent(_) => panic!("An incongruent note has no chromatic index."),
+        }
+    }
+
     pub fn raw_note_to_hz(raw_note: RawNote) -> Hertz {
         // I referenced https://pages.mtu.edu/~suits/notefreqs.html for the frequencies.
 
         // TODO: Implement the MIDI algorithm from https://newt.phys.unsw.edu.au/jw/notes.html instead!
         match raw_note {
             RawNote::Incongruent(hz) => hz,
-            RawNote::C => 16.35,
-            RawNote::D => 18.35,
-            RawNote::E => 20.60,
-            RawNote::F => 21.83,
-            RawNote::G => 24.50,
-            RawNote::A => 27.50,
-            RawNote::B => 30.87,
+            RawNote::C => Hertz::unchecked(16.35),
+            RawNote::D => Hertz::unchecked(18.35),
+            RawNote::E => Hertz::unchecked(20.60),
+            RawNote::F => Hertz::unchecked(21.83),
+            RawNote::G => Hertz::unchecked(24.50),
+            RawNote::A => Hertz::unchecked(27.50),
+            RawNote::B => Hertz::unchecked(30.87),
         }
     }
 
@@ -92,8 +117,11 @@ impl Display for RawNote {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IntoRawNoteError {
     InvalidNoteChar(char),
+    /// A note name string must be exactly one letter.
+    InvalidNoteString(String),
 }
 
 impl TryFrom<char> for RawNote {
@@ -117,6 +145,27 @@ impl TryFrom<char> for RawNote {
     }
 }
 
+impl TryFrom<&str> for RawNote {
+    type Error = IntoRawNoteError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.trim().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(note_char), None) => RawNote::try_from(note_char),
+            _ => Err(IntoRawNoteError::InvalidNoteString(value.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for RawNote {
+    type Error = IntoRawNoteError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        RawNote::try_from(value.as_str())
+    }
+}
+
 impl Add<NoteModifier> for RawNote {
     type Output = AbstractNote;
 
@@ -127,3 +176,54 @@ impl Add<NoteModifier> for RawNote {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    fn natural_notes() -> impl Iterator<Item = RawNote> {
+        RawNote::iter().filter(|raw_note| !matches!(raw_note, RawNote::Incongruent(_)))
+    }
+
+    #[test]
+    fn chromatic_index_matches_steps_from_c() {
+        assert_eq!(RawNote::C.chromatic_index(), 0);
+        assert_eq!(RawNote::B.chromatic_index(), 11);
+
+        let mut semitones_from_c = 0;
+        let mut raw_note = RawNote::C;
+        for _ in 0..7 {
+            assert_eq!(raw_note.chromatic_index() as Semitone, semitones_from_c);
+            let (next_note, semitones_to_next_note) = raw_note.next_note();
+            raw_note = next_note;
+            semitones_from_c += semitones_to_next_note;
+        }
+    }
+
+    #[test]
+    fn round_trips_through_names() {
+        for raw_note in natural_notes() {
+            let name = raw_note.to_string();
+
+            assert_eq!(
+                RawNote::try_from(name.chars().next().unwrap()),
+                Ok(raw_note)
+            );
+            assert_eq!(RawNote::try_from(name.as_str()), Ok(raw_note));
+            assert_eq!(RawNote::try_from(name.to_lowercase()), Ok(raw_note));
+        }
+
+        assert_eq!(
+            RawNote::try_from('H'),
+            Err(IntoRawNoteError::InvalidNoteChar('H'))
+        );
+        assert_eq!(
+            RawNote::try_from("C#"),
+            Err(IntoRawNoteError::InvalidNoteString("C#".to_string()))
+        );
+        assert!(RawNote::try_from("").is_err());
+    }
+}
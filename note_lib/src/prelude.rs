@@ -0,0 +1,16 @@
+//! The types most programs need, for glob importing.
+//!
+//! ```rust
+//! use note_lib::prelude::*;
+//!
+//! let g7 = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+//!
+//! let analysis = Scale::c_major().analyze_chord(&g7);
+//! assert_eq!(analysis.avoid_notes, vec![AbstractNote::from(C)]);
+//! ```
+
+pub use crate::{
+    AbstractNote, Chord, ChordProgression, ChordQuality, Key, ModifierPreference, Note,
+    NoteModifier, Octave, RawNote, Scale, ScaleDegree, ScaleMode, SimpleInterval, A, B, C, D, E, F,
+    G,
+};
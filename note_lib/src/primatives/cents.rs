@@ -0,0 +1,132 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A pitch difference in cents, hundredths of an equal tempered semitone.
+/// Cents can be negative, since they usually describe how far one pitch is
+/// below or above another.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Cents(f64);
+
+impl Cents {
+    pub fn new(value: f64) -> Cents {
+        Cents(value)
+    }
+
+    /// Converts a (possibly fractional) amount of semitones to cents.
+    ///
+    /// ```rust
+    /// use note_lib::Cents;
+    ///
+    /// assert_eq!(Cents::from_semitones(1.5), Cents::new(150.0));
+    /// assert_eq!(Cents::new(-50.0).to_semitones_f64(), -0.5);
+    /// ```
+    pub fn from_semitones(semitones: f64) -> Cents {
+        Cents(semitones * 100.0)
+    }
+
+    pub fn to_semitones_f64(&self) -> f64 {
+        self.0 / 100.0
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    pub fn abs(&self) -> Cents {
+        Cents(self.0.abs())
+    }
+
+    /// Whether this deviation is within `tolerance` of being perfectly in
+    /// tune, in either direction.
+    pub fn is_in_tune(&self, tolerance: Cents) -> bool {
+        self.0.abs() <= tolerance.0
+    }
+}
+
+impl Display for Cents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} cents", self.0)
+    }
+}
+
+impl From<Cents> for f64 {
+    fn from(cents: Cents) -> Self {
+        cents.0
+    }
+}
+
+impl From<f64> for Cents {
+    fn from(value: f64) -> Self {
+        Cents(value)
+    }
+}
+
+impl Add for Cents {
+    type Output = Cents;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Cents(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Cents {
+    type Output = Cents;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Cents(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Cents {
+    type Output = Cents;
+
+    fn neg(self) -> Self::Output {
+        Cents(-self.0)
+    }
+}
+
+impl Mul<f64> for Cents {
+    type Output = Cents;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Cents(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for Cents {
+    type Output = Cents;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Cents(self.0 / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn should_check_tuning() {
+        let tolerance = Cents::new(5.0);
+
+        assert!(Cents::new(4.9).is_in_tune(tolerance));
+        assert!(Cents::new(-5.0).is_in_tune(tolerance));
+        assert!(!Cents::new(-5.1).is_in_tune(tolerance));
+    }
+
+    #[test]
+    fn should_do_arithmetic() {
+        let a = Cents::new(50.0);
+        let b = Cents::new(-20.0);
+
+        assert_eq!(a + b, Cents::new(30.0));
+        assert_eq!(a - b, Cents::new(70.0));
+        assert_eq!(-a, Cents::new(-50.0));
+        assert_eq!(b.abs(), Cents::new(20.0));
+        assert_eq!(a * 2.0, Cents::new(100.0));
+        assert_eq!(a / 2.0, Cents::new(25.0));
+    }
+}
@@ -1 +1,170 @@
-pub type Hertz = f32;
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+};
+
+/// A frequency in hertz. A valid frequency is always positive and finite,
+/// so values like NaN, infinity, zero or negative frequencies are rejected
+/// by [`Hertz::new`].
+///
+/// Arithmetic on frequencies is not validated again. If the result of some
+/// arithmetic might not be a sensible frequency, pass [`Hertz::value`] back
+/// through [`Hertz::new`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "f32", into = "f32"))]
+pub struct Hertz(f32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvalidHertzError {
+    /// The frequency was zero or negative.
+    NotPositive(f32),
+    /// The frequency was NaN or infinite.
+    NotFinite(f32),
+}
+
+impl Display for InvalidHertzError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidHertzError::NotPositive(value) => {
+                write!(f, "frequency {} Hz is not positive", value)
+            }
+            InvalidHertzError::NotFinite(value) => {
+                write!(f, "frequency {} Hz is not finite", value)
+            }
+        }
+    }
+}
+
+impl Hertz {
+    /// Creates a frequency, checking that it is positive and finite.
+    ///
+    /// ```rust
+    /// use note_lib::{Hertz, InvalidHertzError};
+    ///
+    /// assert_eq!(Hertz::new(440.0).unwrap().value(), 440.0);
+    /// assert_eq!(Hertz::new(0.0), Err(InvalidHertzError::NotPositive(0.0)));
+    /// assert!(Hertz::new(f32::NAN).is_err());
+    /// ```
+    pub fn new(value: f32) -> Result<Hertz, InvalidHertzError> {
+        if !value.is_finite() {
+            Err(InvalidHertzError::NotFinite(value))
+        } else if value <= 0.0 {
+            Err(InvalidHertzError::NotPositive(value))
+        } else {
+            Ok(Hertz(value))
+        }
+    }
+
+    /// Creates a frequency without validating it. Only use this for values
+    /// that are known to be valid, like constants.
+    pub(crate) fn unchecked(value: f32) -> Hertz {
+        Hertz(value)
+    }
+
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+/// Defaults to A4, the 440 Hz tuning reference.
+impl Default for Hertz {
+    fn default() -> Self {
+        Hertz(440.0)
+    }
+}
+
+impl Display for Hertz {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} Hz", self.0)
+    }
+}
+
+impl From<Hertz> for f32 {
+    fn from(hertz: Hertz) -> Self {
+        hertz.0
+    }
+}
+
+impl TryFrom<f32> for Hertz {
+    type Error = InvalidHertzError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Hertz::new(value)
+    }
+}
+
+impl Add for Hertz {
+    type Output = Hertz;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Hertz(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Hertz {
+    type Output = Hertz;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Hertz(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f32> for Hertz {
+    type Output = Hertz;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Hertz(self.0 * rhs)
+    }
+}
+
+impl Div<f32> for Hertz {
+    type Output = Hertz;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Hertz(self.0 / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn should_validate() {
+        assert!(Hertz::new(440.0).is_ok());
+        assert!(Hertz::new(0.01).is_ok());
+        assert_eq!(Hertz::new(0.0), Err(InvalidHertzError::NotPositive(0.0)));
+        assert_eq!(
+            Hertz::new(-440.0),
+            Err(InvalidHertzError::NotPositive(-440.0))
+        );
+        assert_eq!(
+            Hertz::new(f32::INFINITY),
+            Err(InvalidHertzError::NotFinite(f32::INFINITY))
+        );
+        assert!(matches!(
+            Hertz::new(f32::NAN),
+            Err(InvalidHertzError::NotFinite(_))
+        ));
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!(Hertz::new(440.0).unwrap().to_string(), "440.00 Hz");
+        assert_eq!(Hertz::new(261.625).unwrap().to_string(), "261.62 Hz");
+    }
+
+    #[test]
+    fn should_do_arithmetic() {
+        let a = Hertz::new(440.0).unwrap();
+        let b = Hertz::new(220.0).unwrap();
+
+        assert_eq!(a + b, Hertz::new(660.0).unwrap());
+        assert_eq!(a - b, b);
+        assert_eq!(b * 2.0, a);
+        assert_eq!(a / 2.0, b);
+        assert!(b < a);
+    }
+}
@@ -1,7 +1,9 @@
+mod cents;
 mod hertz;
 mod octave;
 mod semitone;
 
+pub use cents::*;
 pub use hertz::*;
 pub use octave::*;
 pub use semitone::*;
@@ -1 +1,178 @@
-pub type Octave = i32;
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+/// An octave number in scientific pitch notation, limited to the octaves
+/// MIDI can address (-1 to 9, where C4 is MIDI note 60).
+///
+/// Use [`Octave::new`] to check an octave that comes from user input. The
+/// `From<i32>` conversion used by [`crate::Note::new`] panics on an
+/// out-of-range octave, which catches mistakes like `Note::new(C, 500,
+/// Natural)` where the note is made. Because of that `From` impl, the
+/// standard library's `TryFrom<i32>` is the infallible blanket one, so
+/// [`Octave::new`] is the checked conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Octave(i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OctaveRangeError {
+    /// The octave was below [`Octave::MIN`].
+    TooLow(i32),
+    /// The octave was above [`Octave::MAX`].
+    TooHigh(i32),
+}
+
+impl Octave {
+    /// The lowest octave MIDI can address.
+    pub const MIN: i32 = -1;
+    /// The highest octave MIDI can address.
+    pub const MAX: i32 = 9;
+
+    /// Creates an octave, checking that it is within the MIDI range.
+    ///
+    /// ```rust
+    /// use note_lib::{Octave, OctaveRangeError};
+    ///
+    /// assert_eq!(Octave::new(4).unwrap().value(), 4);
+    /// assert_eq!(Octave::new(-2), Err(OctaveRangeError::TooLow(-2)));
+    /// assert_eq!(Octave::new(500), Err(OctaveRangeError::TooHigh(500)));
+    /// ```
+    pub fn new(value: i32) -> Result<Octave, OctaveRangeError> {
+        if value < Octave::MIN {
+            Err(OctaveRangeError::TooLow(value))
+        } else if value > Octave::MAX {
+            Err(OctaveRangeError::TooHigh(value))
+        } else {
+            Ok(Octave(value))
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.0
+    }
+
+    /// Moves the octave up by `octaves`, or returns an error if that leaves
+    /// the MIDI range.
+    pub fn checked_add(&self, octaves: i32) -> Result<Octave, OctaveRangeError> {
+        Octave::new(self.0 + octaves)
+    }
+
+    /// Moves the octave down by `octaves`, or returns an error if that leaves
+    /// the MIDI range.
+    pub fn checked_sub(&self, octaves: i32) -> Result<Octave, OctaveRangeError> {
+        Octave::new(self.0 - octaves)
+    }
+}
+
+impl Display for OctaveRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OctaveRangeError::TooLow(value) => write!(
+                f,
+                "octave {} is below the lowest octave {}",
+                value,
+                Octave::MIN
+            ),
+            OctaveRangeError::TooHigh(value) => write!(
+                f,
+                "octave {} is above the highest octave {}",
+                value,
+                Octave::MAX
+            ),
+        }
+    }
+}
+
+impl Display for Octave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Panics if the octave is outside the MIDI range. Use [`Octave::new`] for a
+/// checked conversion.
+impl From<i32> for Octave {
+    fn from(value: i32) -> Self {
+        match Octave::new(value) {
+            Ok(octave) => octave,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+impl From<Octave> for i32 {
+    fn from(octave: Octave) -> Self {
+        octave.0
+    }
+}
+
+impl PartialEq<i32> for Octave {
+    fn eq(&self, other: &i32) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Panics if the result is outside the MIDI range. Use
+/// [`Octave::checked_add`] for a checked version.
+impl Add<i32> for Octave {
+    type Output = Octave;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        Octave::from(self.0 + rhs)
+    }
+}
+
+/// Panics if the result is outside the MIDI range. Use
+/// [`Octave::checked_sub`] for a checked version.
+impl Sub<i32> for Octave {
+    type Output = Octave;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        Octave::from(self.0 - rhs)
+    }
+}
+
+/// Deserializing checks the octave range instead of panicking.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Octave {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        Octave::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Octave {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_validate_range() {
+        assert!(Octave::new(Octave::MIN).is_ok());
+        assert!(Octave::new(Octave::MAX).is_ok());
+        assert_eq!(Octave::new(10), Err(OctaveRangeError::TooHigh(10)));
+        assert_eq!(Octave::new(-2), Err(OctaveRangeError::TooLow(-2)));
+    }
+
+    #[test]
+    fn should_do_arithmetic() {
+        let octave = Octave::from(4);
+        assert_eq!(octave + 1, 5);
+        assert_eq!(octave - 5, -1);
+        assert_eq!(octave.checked_add(6), Err(OctaveRangeError::TooHigh(10)));
+        assert_eq!(octave.checked_sub(1), Ok(Octave::from(3)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_on_invalid_conversion() {
+        let _ = Octave::from(500);
+    }
+}
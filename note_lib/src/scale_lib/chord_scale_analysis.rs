@@ -0,0 +1,15 @@
+use crate::{AbstractNote, Note};
+
+/// How the notes of a scale relate to a chord played over it, as used in
+/// chord-scale theory. See [`crate::Scale::analyze_chord`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChordScaleAnalysis {
+    /// The notes of the chord itself.
+    pub chord_tones: Vec<Note>,
+    /// Scale notes outside the chord that are a 9th, 11th or 13th above its
+    /// root, and that can be added to the chord.
+    pub tensions: Vec<AbstractNote>,
+    /// Scale notes outside the chord that clash with it, by being a minor 9th
+    /// or a tritone above one of its notes.
+    pub avoid_notes: Vec<AbstractNote>,
+}
@@ -0,0 +1,600 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+use super::{Scale, ScaleDegree, ScaleMode, ScaleParseError};
+use crate::{AbstractNote, Chord, NoteModifier, Semitone};
+
+/// A key is the tonal center of a piece of music: a tonic note and the mode
+/// built on it. Where a [`Scale`] is just the collection of notes, a key is
+/// used to reason about the harmony around those notes.
+///
+/// Chords produced by a key are rooted in the fourth octave, and the rest of
+/// their notes are stacked above the root.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Key {
+    root: AbstractNote,
+    mode: ScaleMode,
+}
+
+impl Key {
+    pub fn new(root: AbstractNote, mode: ScaleMode) -> Self {
+        Self { root, mode }
+    }
+
+    /// The key of C major. See [`Scale::c_major`].
+    pub fn c_major() -> Self {
+        Scale::c_major().to_key()
+    }
+
+    /// The key of A minor, in the natural minor mode. See [`Scale::a_minor`].
+    pub fn a_minor() -> Self {
+        Scale::a_minor().to_key()
+    }
+
+    pub fn root(&self) -> AbstractNote {
+        self.root
+    }
+
+    pub fn mode(&self) -> ScaleMode {
+        self.mode
+    }
+
+    pub fn scale(&self) -> Scale {
+        Scale::new(self.root, self.mode)
+    }
+
+    /// Gets the scale of this key's notes. This is the same as [`Key::scale`],
+    /// named to pair with [`Scale::to_key`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// let d_dorian = Scale::new(AbstractNote::try_from("D").unwrap(), ScaleMode::Dorian);
+    ///
+    /// assert_eq!(d_dorian.to_key().to_scale(), d_dorian);
+    /// ```
+    pub fn to_scale(&self) -> Scale {
+        self.scale()
+    }
+
+    /// Gets the seven church modes that share this key's notes, one starting on
+    /// each degree. For C major these are C Ionian, D Dorian, E Phrygian,
+    /// F Lydian, G Mixolydian, A Aeolian and B Locrian. Keys in modes that
+    /// aren't taken from the church modes, like harmonic minor, use the modes
+    /// of the major scale on their tonic. See [`Scale::church_modes_iter`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let [_, dorian, ..] = c_major.modal_scales();
+    ///
+    /// assert_eq!(dorian.root(), AbstractNote::try_from("D").unwrap());
+    /// assert_eq!(dorian.mode(), ScaleMode::Dorian);
+    /// ```
+    pub fn modal_scales(&self) -> [Scale; 7] {
+        let mut scales = self.scale().church_modes_iter().collect::<Vec<_>>();
+        if scales.is_empty() {
+            scales = Scale::new(self.root, ScaleMode::Ionian)
+                .church_modes_iter()
+                .collect();
+        }
+
+        scales
+            .try_into()
+            .expect("there are always seven church modes")
+    }
+
+    /// Gets the scale in `mode` that shares this key's notes, like D Dorian for
+    /// C major. Returns `None` if `mode` isn't a church mode.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    ///
+    /// let a_aeolian = c_major.mode_scale(ScaleMode::Aeolian).unwrap();
+    /// assert_eq!(a_aeolian.root(), AbstractNote::try_from("A").unwrap());
+    /// assert_eq!(c_major.mode_scale(ScaleMode::HarmonicMinor), None);
+    /// ```
+    pub fn mode_scale(&self, mode: ScaleMode) -> Option<Scale> {
+        self.modal_scales()
+            .into_iter()
+            .find(|scale| scale.mode() == mode)
+    }
+
+    /// Gets the leading tone of this key and the tonic it resolves up to, like
+    /// `(B, C)` in C major. When the leading tone is in the soprano or tenor,
+    /// it should move to the tonic in the next chord. Returns `None` for keys
+    /// with a [`Scale::subtonic`] instead, like natural minor keys.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, B, C};
+    ///
+    /// assert_eq!(
+    ///     Key::c_major().leading_tone_resolution(),
+    ///     Some((AbstractNote::from(B), AbstractNote::from(C)))
+    /// );
+    /// assert_eq!(Key::a_minor().leading_tone_resolution(), None);
+    /// ```
+    pub fn leading_tone_resolution(&self) -> Option<(AbstractNote, AbstractNote)> {
+        self.scale()
+            .leading_tone()
+            .map(|leading_tone| (leading_tone, self.root))
+    }
+
+    /// Gets the triad built on each of the seven degrees of this key, using
+    /// only notes from its scale.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ChordQuality, Key, ScaleDegree, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let qualities: Vec<ChordQuality> = c_major
+    ///     .diatonic_chords()
+    ///     .iter()
+    ///     .map(|(_, chord)| chord.identify_quality().unwrap().1)
+    ///     .collect();
+    ///
+    /// assert_eq!(qualities[1], ChordQuality::Minor);
+    /// assert_eq!(qualities[6], ChordQuality::Diminished);
+    /// ```
+    pub fn diatonic_chords(&self) -> Vec<(ScaleDegree, Chord)> {
+        let scale = self.scale();
+
+        ScaleDegree::iter()
+            .filter(|degree| *degree != ScaleDegree::Octave)
+            .map(|degree| (degree, scale.diatonic_chord_at_degree(degree)))
+            .collect()
+    }
+
+    /// Gets the dominant seventh chord that resolves to each of the degrees
+    /// two through seven of this key. These are the chords written as V7/x,
+    /// rooted a perfect fifth above the degree they resolve to.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ChordQuality, Key, ScaleDegree, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let (degree, chord) = c_major.secondary_dominants().remove(3);
+    /// let (root, quality) = chord.identify_quality().unwrap();
+    ///
+    /// // The dominant of G is D7.
+    /// assert_eq!(degree, ScaleDegree::Fifth);
+    /// assert_eq!(AbstractNote::from(root), AbstractNote::try_from("D").unwrap());
+    /// assert_eq!(quality, ChordQuality::Dominant7th);
+    /// ```
+    pub fn secondary_dominants(&self) -> Vec<(ScaleDegree, Chord)> {
+        [
+            ScaleDegree::Second,
+            ScaleDegree::Third,
+            ScaleDegree::Fourth,
+            ScaleDegree::Fifth,
+            ScaleDegree::Sixth,
+            ScaleDegree::Seventh,
+        ]
+        .into_iter()
+        .map(|degree| {
+            let target = self.mode.spelled_note_at_degree(self.root, degree);
+            let dominant_root =
+                ScaleMode::Ionian.spelled_note_at_degree(target, ScaleDegree::Fifth);
+            let dominant_scale = Scale::new(dominant_root, ScaleMode::Mixolydian);
+
+            (
+                degree,
+                dominant_scale.stack_notes(ScaleDegree::First, &[2, 4, 6]),
+            )
+        })
+        .collect()
+    }
+
+    /// Gets the common chords a major key borrows from its parallel minor: the
+    /// major triads on the flattened third, sixth and seventh degrees (bIII,
+    /// bVI and bVII). Keys in any other mode return no chords.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ScaleDegree, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let (degree, chord) = &c_major.borrowed_chords()[0];
+    ///
+    /// // bIII in C major is Eb major.
+    /// assert_eq!(*degree, ScaleDegree::Third);
+    /// assert_eq!(AbstractNote::from(chord.notes()[0]), AbstractNote::try_from("Eb").unwrap());
+    /// ```
+    pub fn borrowed_chords(&self) -> Vec<(ScaleDegree, Chord)> {
+        if self.mode != ScaleMode::Ionian {
+            return vec![];
+        }
+
+        let parallel_minor = Scale::new(self.root, ScaleMode::Aeolian);
+
+        [ScaleDegree::Third, ScaleDegree::Sixth, ScaleDegree::Seventh]
+            .into_iter()
+            .map(|degree| (degree, parallel_minor.diatonic_chord_at_degree(degree)))
+            .collect()
+    }
+
+    /// Gets the key rooted on the tritone substitute of this key's dominant. A
+    /// tritone substitution replaces the dominant with the dominant a tritone
+    /// away from it, like Db7 for G7 in C major, so C major gives Db major. The
+    /// root is spelled as the flattened second of this key, and the mode is
+    /// kept.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ScaleMode};
+    ///
+    /// let c_major = Key::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let d_flat_major = Key::new(AbstractNote::try_from("Db").unwrap(), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_major.tritone_substitution(), d_flat_major);
+    /// ```
+    pub fn tritone_substitution(&self) -> Key {
+        Key::new(
+            ScaleMode::Phrygian.spelled_note_at_degree(self.root, ScaleDegree::Second),
+            self.mode,
+        )
+    }
+
+    /// Checks whether every note of the chord is in this key's scale. Notes are
+    /// compared by pitch class, so enharmonic spellings still count.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, Note, NoteModifier, ScaleMode, A, C, E, F, G};
+    ///
+    /// let c_major = Key::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let c_triad = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(E, 4, NoteModifier::Natural)
+    ///     + Note::new(G, 4, NoteModifier::Natural);
+    /// let non_diatonic = Note::new(C, 4, NoteModifier::Natural)
+    ///     + Note::new(F, 4, NoteModifier::Sharp)
+    ///     + Note::new(A, 4, NoteModifier::Natural);
+    ///
+    /// assert!(c_major.is_diatonic(&c_triad));
+    /// assert!(!c_major.is_diatonic(&non_diatonic));
+    /// ```
+    pub fn is_diatonic(&self, chord: &Chord) -> bool {
+        let scale_pitch_classes: Vec<u8> = self
+            .scale()
+            .all_notes()
+            .iter()
+            .map(|note| note.chromatic_index())
+            .collect();
+
+        chord
+            .pitch_classes()
+            .iter()
+            .all(|pitch_class| scale_pitch_classes.contains(pitch_class))
+    }
+
+    /// Gets the same key spelled from an enharmonic root, like Gb major for F#
+    /// major. Only spellings that can be written with a regular key signature,
+    /// without any double sharps or double flats, are considered. Keys like C
+    /// major have no such spelling, so `None` is returned.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Key, ScaleMode};
+    ///
+    /// let b_major = Key::new(AbstractNote::try_from("B").unwrap(), ScaleMode::Ionian);
+    /// let c_flat_major = Key::new(AbstractNote::try_from("Cb").unwrap(), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(b_major.enharmonic_equivalent(), Some(c_flat_major));
+    /// ```
+    pub fn enharmonic_equivalent(&self) -> Option<Key> {
+        let pitch_class = self.root.chromatic_index() as Semitone;
+
+        [
+            self.root.raw_note.prev_note().0,
+            self.root.raw_note.next_note().0,
+        ]
+        .into_iter()
+        .filter_map(|raw_note| {
+            let natural_pitch_class = AbstractNote::from(raw_note).chromatic_index() as Semitone;
+            let modifier_semitones = (pitch_class - natural_pitch_class + 6).rem_euclid(12) - 6;
+
+            match modifier_semitones {
+                -1..=1 => NoteModifier::from_semitones(modifier_semitones)
+                    .map(|modifier| AbstractNote { raw_note, modifier }),
+                _ => None,
+            }
+        })
+        .map(|root| Key::new(root, self.mode))
+        .find(|key| {
+            key.scale().all_notes().iter().all(|note| {
+                !matches!(
+                    note.modifier,
+                    NoteModifier::DoubleSharp | NoteModifier::DoubleFlat
+                )
+            })
+        })
+    }
+}
+
+/// Keys are parsed the same way as scales.
+pub type KeyParseError = ScaleParseError;
+
+/// Parses a key from a tonic followed by a mode, like "C major" or "Am",
+/// the same way as [`Scale`] is parsed.
+///
+/// ```rust
+/// use note_lib::{AbstractNote, Key, ScaleMode};
+///
+/// let key: Key = "Eb major".parse().unwrap();
+/// assert_eq!(key, Key::new(AbstractNote::try_from("Eb").unwrap(), ScaleMode::Ionian));
+/// ```
+impl FromStr for Key {
+    type Err = KeyParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let scale: Scale = s.parse()?;
+        Ok(Key::new(scale.root(), scale.mode()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::ChordQuality;
+
+    use super::*;
+
+    fn note(name: &str) -> AbstractNote {
+        AbstractNote::try_from(name).unwrap()
+    }
+
+    fn note_names(chord: &Chord) -> Vec<String> {
+        chord.notes().iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn gets_diatonic_chords() {
+        let expected = [
+            (ScaleDegree::First, ["D4", "F#4", "A4"], ChordQuality::Major),
+            (ScaleDegree::Second, ["E4", "G4", "B4"], ChordQuality::Minor),
+            (
+                ScaleDegree::Third,
+                ["F#4", "A4", "C#5"],
+                ChordQuality::Minor,
+            ),
+            (ScaleDegree::Fourth, ["G4", "B4", "D5"], ChordQuality::Major),
+            (ScaleDegree::Fifth, ["A4", "C#5", "E5"], ChordQuality::Major),
+            (ScaleDegree::Sixth, ["B4", "D5", "F#5"], ChordQuality::Minor),
+            (
+                ScaleDegree::Seventh,
+                ["C#4", "E4", "G4"],
+                ChordQuality::Diminished,
+            ),
+        ];
+
+        let d_major = Key::new(note("D"), ScaleMode::Ionian);
+        let chords = d_major.diatonic_chords();
+
+        assert_eq!(chords.len(), expected.len());
+        for ((degree, chord), (expected_degree, expected_notes, expected_quality)) in
+            chords.iter().zip(expected)
+        {
+            assert_eq!(*degree, expected_degree);
+            assert_eq!(note_names(chord), expected_notes);
+            assert_eq!(
+                chord.identify_quality().map(|(_, quality)| quality),
+                Some(expected_quality)
+            );
+            assert!(d_major.is_diatonic(chord));
+        }
+    }
+
+    #[test]
+    fn gets_secondary_dominants() {
+        let c_major = Key::new(note("C"), ScaleMode::Ionian);
+        let dominants = c_major.secondary_dominants();
+
+        let expected = [
+            (ScaleDegree::Second, ["A4", "C#5", "E5", "G5"]),
+            (ScaleDegree::Third, ["B4", "D#5", "F#5", "A5"]),
+            (ScaleDegree::Fourth, ["C4", "E4", "G4", "Bb4"]),
+            (ScaleDegree::Fifth, ["D4", "F#4", "A4", "C5"]),
+            (ScaleDegree::Sixth, ["E4", "G#4", "B4", "D5"]),
+            (ScaleDegree::Seventh, ["F#4", "A#4", "C#5", "E5"]),
+        ];
+
+        assert_eq!(dominants.len(), expected.len());
+        for ((degree, chord), (expected_degree, expected_notes)) in dominants.iter().zip(expected) {
+            assert_eq!(*degree, expected_degree);
+            assert_eq!(note_names(chord), expected_notes);
+            assert_eq!(
+                chord.identify_quality().map(|(_, quality)| quality),
+                Some(ChordQuality::Dominant7th)
+            );
+        }
+    }
+
+    #[test]
+    fn gets_borrowed_chords() {
+        let c_major = Key::new(note("C"), ScaleMode::Ionian);
+        let borrowed = c_major.borrowed_chords();
+
+        let expected = [
+            (ScaleDegree::Third, ["Eb4", "G4", "Bb4"]),
+            (ScaleDegree::Sixth, ["Ab4", "C5", "Eb5"]),
+            (ScaleDegree::Seventh, ["Bb4", "D5", "F5"]),
+        ];
+
+        let scale_pitch_classes: Vec<u8> = c_major
+            .scale()
+            .all_notes()
+            .iter()
+            .map(|n| n.chromatic_index())
+            .collect();
+
+        assert_eq!(borrowed.len(), expected.len());
+        for ((degree, chord), (expected_degree, expected_notes)) in borrowed.iter().zip(expected) {
+            assert_eq!(*degree, expected_degree);
+            assert_eq!(note_names(chord), expected_notes);
+            assert!(!chord
+                .pitch_classes()
+                .iter()
+                .all(|pitch_class| scale_pitch_classes.contains(pitch_class)));
+        }
+
+        assert!(Key::new(note("A"), ScaleMode::Aeolian)
+            .borrowed_chords()
+            .is_empty());
+    }
+
+    #[test]
+    fn checks_diatonic_chords() {
+        let c_major = Key::new(note("C"), ScaleMode::Ionian);
+        let chord = |names: &[&str]| Chord::new(names.iter().map(|n| note(n).to_note(4)).collect());
+
+        assert!(c_major.is_diatonic(&chord(&["C", "E", "G"])));
+        assert!(c_major.is_diatonic(&chord(&["F", "A", "C"])));
+        assert!(c_major.is_diatonic(&chord(&["B", "D", "F"])));
+        assert!(!c_major.is_diatonic(&chord(&["C", "F#", "A"])));
+
+        for (_, borrowed) in c_major.borrowed_chords() {
+            assert!(!c_major.is_diatonic(&borrowed));
+        }
+    }
+
+    #[test]
+    fn gets_enharmonic_equivalents() {
+        let pairs = [
+            (("B", ScaleMode::Ionian), ("Cb", ScaleMode::Ionian)),
+            (("F#", ScaleMode::Ionian), ("Gb", ScaleMode::Ionian)),
+            (("C#", ScaleMode::Ionian), ("Db", ScaleMode::Ionian)),
+            (("G#", ScaleMode::Aeolian), ("Ab", ScaleMode::Aeolian)),
+            (("D#", ScaleMode::Aeolian), ("Eb", ScaleMode::Aeolian)),
+            (("A#", ScaleMode::Aeolian), ("Bb", ScaleMode::Aeolian)),
+        ];
+
+        for ((root, mode), (other_root, other_mode)) in pairs {
+            let key = Key::new(note(root), mode);
+            let other = Key::new(note(other_root), other_mode);
+
+            assert_eq!(key.enharmonic_equivalent(), Some(other));
+            assert_eq!(other.enharmonic_equivalent(), Some(key));
+        }
+
+        for root in ["C", "G", "D", "E", "F", "Bb"] {
+            assert_eq!(
+                Key::new(note(root), ScaleMode::Ionian).enharmonic_equivalent(),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn gets_tritone_substitution() {
+        let key = |name: &str, mode| Key::new(note(name), mode);
+
+        assert_eq!(
+            key("C", ScaleMode::Ionian).tritone_substitution(),
+            key("Db", ScaleMode::Ionian)
+        );
+        assert_eq!(
+            key("G", ScaleMode::Ionian).tritone_substitution(),
+            key("Ab", ScaleMode::Ionian)
+        );
+        assert_eq!(
+            key("A", ScaleMode::Aeolian).tritone_substitution(),
+            key("Bb", ScaleMode::Aeolian)
+        );
+
+        // The new root is a tritone away from the original dominant.
+        let c_major = key("C", ScaleMode::Ionian);
+        let (_, dominant) = c_major.root().cycle_of_fifths_neighbors();
+        let substitute_root = c_major.tritone_substitution().root();
+        assert_eq!(
+            (substitute_root.chromatic_index() + 12 - dominant.chromatic_index()) % 12,
+            6
+        );
+    }
+
+    #[test]
+    fn parses_keys() {
+        assert_eq!(
+            "D dorian".parse(),
+            Ok(Key::new(note("D"), ScaleMode::Dorian))
+        );
+        assert_eq!("F#m".parse(), Ok(Key::new(note("F#"), ScaleMode::Aeolian)));
+        assert_eq!("".parse::<Key>(), Err(KeyParseError::MissingRoot));
+    }
+
+    #[test]
+    fn gets_modal_scales() {
+        let c_major = Key::new(note("C"), ScaleMode::Ionian);
+        let modal_scales = c_major.modal_scales();
+
+        let expected = [
+            ("C", ScaleMode::Ionian),
+            ("D", ScaleMode::Dorian),
+            ("E", ScaleMode::Phrygian),
+            ("F", ScaleMode::Lydian),
+            ("G", ScaleMode::Mixolydian),
+            ("A", ScaleMode::Aeolian),
+            ("B", ScaleMode::Locrian),
+        ];
+        for (scale, (root, mode)) in modal_scales.iter().zip(expected) {
+            assert!(scale.structural_eq(&Scale::new(note(root), mode)));
+            assert_eq!(scale, &c_major.scale());
+        }
+
+        for (root, mode) in expected {
+            assert_eq!(c_major.mode_scale(mode).unwrap().root(), note(root));
+        }
+        assert_eq!(c_major.mode_scale(ScaleMode::Blues), None);
+
+        // Other modes start from their own tonic.
+        let d_dorian = Key::new(note("D"), ScaleMode::Dorian);
+        assert!(d_dorian.modal_scales()[6].structural_eq(&c_major.scale()));
+    }
+
+    #[test]
+    fn converts_between_keys_and_scales() {
+        for mode in ScaleMode::iter() {
+            for root in ["C", "F#", "Bb", "Cb"] {
+                let scale = Scale::new(note(root), mode);
+                let key = scale.to_key();
+
+                assert_eq!(key, Key::new(note(root), mode));
+                assert_eq!(key.to_scale(), scale);
+                assert_eq!(key.to_scale().root(), note(root));
+                assert_eq!(key.to_scale().mode(), mode);
+                assert_eq!(key.to_scale().to_key(), key);
+            }
+        }
+    }
+
+    #[test]
+    fn builds_well_known_keys() {
+        assert_eq!(Key::c_major(), Key::new(note("C"), ScaleMode::Ionian));
+        assert_eq!(Key::a_minor(), Key::new(note("A"), ScaleMode::Aeolian));
+        assert_eq!(Key::c_major().scale(), Key::a_minor().scale());
+    }
+
+    #[test]
+    fn resolves_leading_tones() {
+        let major_keys = [("C", "B"), ("G", "F#"), ("Eb", "D"), ("F#", "E#")];
+        for (tonic, leading_tone) in major_keys {
+            assert_eq!(
+                Key::new(note(tonic), ScaleMode::Ionian).leading_tone_resolution(),
+                Some((note(leading_tone), note(tonic)))
+            );
+        }
+
+        assert_eq!(
+            Key::new(note("A"), ScaleMode::HarmonicMinor).leading_tone_resolution(),
+            Some((note("G#"), note("A")))
+        );
+
+        for tonic in ["A", "C#", "Bb"] {
+            let key = Key::new(note(tonic), ScaleMode::Aeolian);
+            assert_eq!(key.leading_tone_resolution(), None);
+        }
+        assert_eq!(
+            Key::new(note("G"), ScaleMode::Mixolydian).leading_tone_resolution(),
+            None
+        );
+    }
+}
@@ -1,8 +1,12 @@
+mod chord_scale_analysis;
+mod key;
 mod scale;
 mod scale_degree;
 mod scale_mode;
 mod scale_mode_note_iter;
 
+pub use chord_scale_analysis::*;
+pub use key::*;
 pub use scale::*;
 pub use scale_degree::*;
 pub use scale_mode::*;
@@ -1,5 +1,19 @@
-use super::{ScaleMode, ScaleNoteIter};
-use crate::AbstractNote;
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use strum::IntoEnumIterator;
+
+use super::{ChordScaleAnalysis, Key, ScaleDegree, ScaleMode, ScaleNoteIter, CHURCH_MODES};
+use crate::{
+    AbstractNote, Chord, MidiRangeError, Note, NoteRangeError, RawNote, Semitone, SimpleInterval,
+    SimpleIntervalNumber,
+};
+
+/// The octave that chords built from a scale are rooted in.
+const CHORD_ROOT_OCTAVE: i32 = 4;
 
 /// Consider implementing scales.
 ///
@@ -9,7 +23,7 @@ use crate::AbstractNote;
 /// - Scales can be in a particular mode, which will produce a different pattern of notes
 ///
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct Scale {
     root_note: AbstractNote,
     mode: ScaleMode,
@@ -19,6 +33,802 @@ impl Scale {
     pub fn new(root_note: AbstractNote, mode: ScaleMode) -> Self {
         Self { root_note, mode }
     }
+
+    /// The C major scale, C D E F G A B.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// assert_eq!(
+    ///     Scale::c_major(),
+    ///     Scale::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian)
+    /// );
+    /// ```
+    pub fn c_major() -> Self {
+        Self::new(AbstractNote::from(RawNote::C), ScaleMode::Ionian)
+    }
+
+    /// The A natural minor scale, A B C D E F G.
+    pub fn a_minor() -> Self {
+        Self::new(AbstractNote::from(RawNote::A), ScaleMode::Aeolian)
+    }
+
+    pub fn root(&self) -> AbstractNote {
+        self.root_note
+    }
+
+    pub fn mode(&self) -> ScaleMode {
+        self.mode
+    }
+
+    /// Gets the key with this scale's root as its tonic, in this scale's mode.
+    /// See [`Key::to_scale`] for the way back.
+    pub fn to_key(&self) -> Key {
+        Key::new(self.root_note, self.mode)
+    }
+
+    /// Gets the set of pitch classes (0 through 11, where C is 0) in this
+    /// scale, regardless of spelling.
+    pub fn pitch_classes(&self) -> BTreeSet<u8> {
+        self.all_notes()
+            .iter()
+            .map(|note| note.chromatic_index())
+            .collect()
+    }
+
+    /// Checks whether this scale has the same root spelling and mode as
+    /// `other`. Unlike `==`, which only compares pitches, C# major and Db
+    /// major aren't structurally equal, and neither are C major and A minor.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// let c_sharp_major = Scale::new(AbstractNote::try_from("C#").unwrap(), ScaleMode::Ionian);
+    /// let d_flat_major = Scale::new(AbstractNote::try_from("Db").unwrap(), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_sharp_major, d_flat_major);
+    /// assert!(!c_sharp_major.structural_eq(&d_flat_major));
+    /// ```
+    pub fn structural_eq(&self, other: &Scale) -> bool {
+        self.root_note == other.root_note && self.mode == other.mode
+    }
+
+    /// Names this scale by its root and the everyday name of its mode, like
+    /// "C Major" rather than "C Ionian". Modes without an everyday name use
+    /// their mode name, like "G Dorian". See [`ScaleMode::common_name`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// let a_minor = Scale::new(AbstractNote::try_from("A").unwrap(), ScaleMode::Aeolian);
+    /// assert_eq!(a_minor.common_scale_name(), "A Natural Minor");
+    /// ```
+    pub fn common_scale_name(&self) -> String {
+        format!(
+            "{} {}",
+            self.root_note,
+            self.mode.common_name().unwrap_or(self.mode.name())
+        )
+    }
+
+    /// Gets the pentatonic scale that is left after taking the two notes a half
+    /// step above another note out of this scale. Major scales give the major
+    /// pentatonic scale and natural minor scales give the minor pentatonic
+    /// scale, both from the same root. Other modes return `None`.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let pentatonic = c_major.pentatonic_subset().unwrap();
+    ///
+    /// assert_eq!(pentatonic.mode(), ScaleMode::PentatonicMajor);
+    /// assert_eq!(pentatonic.all_notes().len(), 5);
+    /// ```
+    pub fn pentatonic_subset(&self) -> Option<Scale> {
+        match self.mode {
+            ScaleMode::Ionian => Some(Scale::new(self.root_note, ScaleMode::PentatonicMajor)),
+            ScaleMode::Aeolian => Some(Scale::new(self.root_note, ScaleMode::PentatonicMinor)),
+            _ => None,
+        }
+    }
+
+    /// Gets the blues scale built on this scale's minor pentatonic notes, which
+    /// is this scale's [`Scale::pentatonic_subset`] plus the blue note. For a
+    /// natural minor scale it shares the root, and for a major scale it starts
+    /// on the relative minor, so C major gives A blues. Other modes return
+    /// `None`.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, A, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let blues = c_major.blues_subset().unwrap();
+    ///
+    /// assert!(blues.structural_eq(&Scale::new(AbstractNote::from(A), ScaleMode::Blues)));
+    /// ```
+    pub fn blues_subset(&self) -> Option<Scale> {
+        match self.mode {
+            ScaleMode::Ionian => Some(Scale::new(
+                self.root_note
+                    .spelled_add_interval(SimpleInterval::MajorSixth),
+                ScaleMode::Blues,
+            )),
+            ScaleMode::Aeolian => Some(Scale::new(self.root_note, ScaleMode::Blues)),
+            _ => None,
+        }
+    }
+
+    /// Iterates the seven church modes that share this scale's notes, each
+    /// starting on the next note of the scale. The iterator starts with this
+    /// scale itself, so D Dorian gives D Dorian, E Phrygian, and so on up to
+    /// C Ionian. Pentatonic and blues scales use the modes of their
+    /// [`ScaleMode::parent_mode`], and scales with no church mode parent, like
+    /// harmonic minor, give nothing.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let modes: Vec<Scale> = c_major.church_modes_iter().collect();
+    ///
+    /// assert_eq!(modes.len(), 7);
+    /// assert_eq!(modes[1].root().to_string(), "D");
+    /// assert_eq!(modes[1].mode(), ScaleMode::Dorian);
+    /// ```
+    pub fn church_modes_iter(&self) -> impl Iterator<Item = Scale> {
+        let parent_mode = self.mode.parent_mode();
+        let parent_notes = Scale::new(self.root_note, parent_mode).all_notes();
+
+        CHURCH_MODES
+            .iter()
+            .position(|mode| *mode == parent_mode)
+            .into_iter()
+            .flat_map(move |start| {
+                let parent_notes = parent_notes.clone();
+                (0..CHURCH_MODES.len()).map(move |step| {
+                    Scale::new(
+                        parent_notes[step],
+                        CHURCH_MODES[(start + step) % CHURCH_MODES.len()],
+                    )
+                })
+            })
+    }
+
+    /// Gets the church mode that starts on `degree` of this scale and shares
+    /// its notes, like Mixolydian on the fifth degree of a major scale. The
+    /// octave starts the same mode as the first degree. Returns `None` for
+    /// scales with no church mode parent, like harmonic minor. See
+    /// [`Scale::church_modes_iter`].
+    ///
+    /// ```rust
+    /// use note_lib::{Scale, ScaleDegree, ScaleMode};
+    ///
+    /// assert_eq!(Scale::c_major().mode_at_degree(ScaleDegree::Fifth), Some(ScaleMode::Mixolydian));
+    /// ```
+    pub fn mode_at_degree(&self, degree: ScaleDegree) -> Option<ScaleMode> {
+        self.starting_at_degree(degree).map(|scale| scale.mode())
+    }
+
+    /// Gets the scale that starts on `degree` of this scale in the mode from
+    /// [`Scale::mode_at_degree`], like G Mixolydian for the fifth degree of C
+    /// major.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleDegree, ScaleMode, G};
+    ///
+    /// let g_mixolydian = Scale::c_major().starting_at_degree(ScaleDegree::Fifth).unwrap();
+    ///
+    /// assert_eq!(g_mixolydian.root(), AbstractNote::from(G));
+    /// assert_eq!(g_mixolydian.mode(), ScaleMode::Mixolydian);
+    /// ```
+    pub fn starting_at_degree(&self, degree: ScaleDegree) -> Option<Scale> {
+        let steps = ScaleDegree::iter()
+            .position(|scale_degree| scale_degree == degree)
+            .unwrap_or(0)
+            % CHURCH_MODES.len();
+
+        self.church_modes_iter().nth(steps)
+    }
+
+    /// Sorts the notes of this scale against `chord`, following chord-scale
+    /// theory. Scale notes outside the chord are avoid notes when they are a
+    /// minor 9th or a tritone above a chord tone, and are otherwise tensions
+    /// if they are a 9th, 11th or 13th above the chord's root. Whether an
+    /// avoid note really clashes depends on how it's used, for example on a
+    /// weak beat it's usually fine.
+    ///
+    /// The chord's root is found with [`Chord::identify_quality`], falling
+    /// back to its lowest note. Tensions and avoid notes are listed going up
+    /// from the root.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, ChordQuality, Note, NoteModifier, Scale, ScaleMode, G};
+    ///
+    /// let c_major = Scale::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    /// let g7 = ChordQuality::Dominant7th.to_chord(Note::new(G, 3, NoteModifier::Natural));
+    ///
+    /// let analysis = c_major.analyze_chord(&g7);
+    /// assert_eq!(analysis.avoid_notes, vec![AbstractNote::try_from("C").unwrap()]);
+    /// ```
+    pub fn analyze_chord(&self, chord: &Chord) -> ChordScaleAnalysis {
+        let root = match chord.identify_quality() {
+            Some((root, _)) => AbstractNote::from(root),
+            None => match chord.sort_by_pitch().notes().first() {
+                Some(bass) => AbstractNote::from(*bass),
+                None => return ChordScaleAnalysis::default(),
+            },
+        };
+
+        let chord_pitch_classes = chord.pitch_classes();
+        let semitones_above = |note: AbstractNote, from: Semitone| {
+            (note.chromatic_index() as Semitone - from).rem_euclid(12)
+        };
+
+        let mut other_notes: Vec<AbstractNote> = self
+            .all_notes()
+            .into_iter()
+            .filter(|note| !chord_pitch_classes.contains(&note.chromatic_index()))
+            .collect();
+        other_notes.sort_by_key(|note| semitones_above(*note, root.chromatic_index() as Semitone));
+
+        let mut analysis = ChordScaleAnalysis {
+            chord_tones: chord.notes().to_vec(),
+            ..Default::default()
+        };
+
+        for note in other_notes {
+            let clashes = chord_pitch_classes
+                .iter()
+                .any(|chord_tone| matches!(semitones_above(note, *chord_tone as Semitone), 1 | 6));
+
+            if clashes {
+                analysis.avoid_notes.push(note);
+            } else if matches!(
+                root.diatonic_interval_to(note),
+                SimpleIntervalNumber::Second
+                    | SimpleIntervalNumber::Fourth
+                    | SimpleIntervalNumber::Sixth
+            ) {
+                analysis.tensions.push(note);
+            }
+        }
+
+        analysis
+    }
+
+    /// Gets the notes of this scale from the root up to, but not including,
+    /// the octave. Each note is spelled using the scale's key signature.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// let scale = Scale::new(AbstractNote::try_from("F").unwrap(), ScaleMode::Ionian);
+    /// let notes: Vec<String> = scale.all_notes().iter().map(|n| n.to_string()).collect();
+    ///
+    /// assert_eq!(notes, vec!["F", "G", "A", "Bb", "C", "D", "E"]);
+    /// ```
+    pub fn all_notes(&self) -> Vec<AbstractNote> {
+        self.mode
+            .intervals()
+            .into_iter()
+            .map(|interval| self.root_note.spelled_add_interval(interval))
+            .collect()
+    }
+
+    /// Gets the notes of this scale whose pitch class is also in `other`,
+    /// spelled the way this scale spells them.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C, G};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let g_major = Scale::new(AbstractNote::from(G), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_major.common_tones(&g_major).len(), 6);
+    /// ```
+    pub fn common_tones(&self, other: &Scale) -> Vec<AbstractNote> {
+        let other_pitch_classes = other.pitch_classes();
+
+        self.all_notes()
+            .into_iter()
+            .filter(|note| other_pitch_classes.contains(&note.chromatic_index()))
+            .collect()
+    }
+
+    /// Gets the notes of this scale whose pitch class isn't in `other`. The
+    /// notes are spelled the way this scale spells them.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C, F, G};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let g_major = Scale::new(AbstractNote::from(G), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_major.difference(&g_major), vec![AbstractNote::from(F)]);
+    /// ```
+    pub fn difference(&self, other: &Scale) -> Vec<AbstractNote> {
+        let other_pitch_classes = other.pitch_classes();
+
+        self.all_notes()
+            .into_iter()
+            .filter(|note| !other_pitch_classes.contains(&note.chromatic_index()))
+            .collect()
+    }
+
+    /// Given a note, return the enharmonic spelling this scale uses for that
+    /// pitch class. If the pitch class is not in the scale, the note is
+    /// returned unchanged.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode};
+    ///
+    /// let g_major = Scale::new(AbstractNote::try_from("G").unwrap(), ScaleMode::Ionian);
+    ///
+    /// // G major uses F#, not Gb.
+    /// let spelled = g_major.spell_note(AbstractNote::try_from("Gb").unwrap());
+    /// assert_eq!(spelled, AbstractNote::try_from("F#").unwrap());
+    /// ```
+    pub fn spell_note(&self, note: AbstractNote) -> AbstractNote {
+        let pitch_class = note.chromatic_index();
+
+        self.all_notes()
+            .into_iter()
+            .find(|scale_note| scale_note.chromatic_index() == pitch_class)
+            .unwrap_or(note)
+    }
+
+    /// Respells each note of `chord` the way this scale spells its pitch
+    /// class, using [`Chord::respell_for_key`]. Notes keep their pitch, and
+    /// notes outside the scale are spelled with the accidentals of its key.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, C, D, F, G};
+    ///
+    /// let g_major = Scale::new(AbstractNote::from(G), ScaleMode::Ionian);
+    /// let chord = Note::new(D, 4, NoteModifier::Natural) + Note::new(G, 4, NoteModifier::Flat);
+    ///
+    /// assert_eq!(
+    ///     g_major.spell_chord(&chord),
+    ///     Note::new(D, 4, NoteModifier::Natural) + Note::new(F, 4, NoteModifier::Sharp)
+    /// );
+    /// ```
+    pub fn spell_chord(&self, chord: &Chord) -> Chord {
+        chord.respell_for_key(self.root_note, self.mode)
+    }
+
+    /// Splits this scale into its lower tetrachord, degrees I to IV, and its
+    /// upper tetrachord, degrees V to VIII.
+    ///
+    /// Both tetrachords of a major scale have the same whole, whole, half step
+    /// pattern, and are joined by a whole step. That is why the upper
+    /// tetrachord of one major scale is the lower tetrachord of the major scale
+    /// a fifth above.
+    ///
+    /// Pentatonic and blues scales don't have seven degrees, so they are split
+    /// by the degrees of their [`ScaleMode::parent_mode`]. That means their
+    /// tetrachords hold the notes of the parent scale, like the F and B of C
+    /// major for C major pentatonic.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, A, B, C, D, E, F, G};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let (lower, upper) = c_major.tetrachords();
+    ///
+    /// assert_eq!(lower, [C, D, E, F].map(AbstractNote::from));
+    /// assert_eq!(upper, [G, A, B, C].map(AbstractNote::from));
+    /// ```
+    pub fn tetrachords(&self) -> (Vec<AbstractNote>, Vec<AbstractNote>) {
+        let notes: Vec<AbstractNote> = ScaleDegree::iter()
+            .map(|degree| self.mode.spelled_note_at_degree(self.root_note, degree))
+            .collect();
+        let (lower, upper) = notes.split_at(4);
+
+        (lower.to_vec(), upper.to_vec())
+    }
+
+    /// Whether the seventh degree of this scale is a half step below the
+    /// octave, so that it leads back to the root.
+    pub fn has_leading_tone(&self) -> bool {
+        self.mode
+            .intervals()
+            .contains(&SimpleInterval::MajorSeventh)
+    }
+
+    /// Gets the leading tone of this scale, the seventh a half step below the
+    /// root, if the scale has one. Major and harmonic minor scales have a
+    /// leading tone, while natural minor scales have a [`Scale::subtonic`]
+    /// instead.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, B, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// assert_eq!(c_major.leading_tone(), Some(AbstractNote::from(B)));
+    /// ```
+    pub fn leading_tone(&self) -> Option<AbstractNote> {
+        self.seventh_with_interval(SimpleInterval::MajorSeventh)
+    }
+
+    /// Gets the subtonic of this scale, the seventh a whole step below the
+    /// root, if the scale has one. See [`Scale::leading_tone`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C};
+    ///
+    /// let c_minor = Scale::new(AbstractNote::from(C), ScaleMode::Aeolian);
+    /// assert_eq!(c_minor.subtonic(), Some(AbstractNote::try_from("Bb").unwrap()));
+    /// assert_eq!(c_minor.leading_tone(), None);
+    /// ```
+    pub fn subtonic(&self) -> Option<AbstractNote> {
+        self.seventh_with_interval(SimpleInterval::MinorSeventh)
+    }
+
+    fn seventh_with_interval(&self, interval: SimpleInterval) -> Option<AbstractNote> {
+        self.mode
+            .intervals()
+            .contains(&interval)
+            .then(|| self.root_note.spelled_add_interval(interval))
+    }
+
+    /// Finds which degree of this scale a note is, comparing pitch classes so
+    /// that enharmonic spellings are found too. Returns `None` if the note is
+    /// not in the scale. The degree is counted by letter name from the root,
+    /// so the G of C major pentatonic is still its fifth degree.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleDegree, ScaleMode};
+    ///
+    /// let c_major = Scale::new(AbstractNote::try_from("C").unwrap(), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_major.degree_of_note(AbstractNote::try_from("G").unwrap()), Some(ScaleDegree::Fifth));
+    /// assert_eq!(c_major.degree_of_note(AbstractNote::try_from("F#").unwrap()), None);
+    /// ```
+    pub fn degree_of_note(&self, note: AbstractNote) -> Option<ScaleDegree> {
+        let pitch_class = note.chromatic_index();
+
+        let scale_note = self
+            .all_notes()
+            .into_iter()
+            .find(|scale_note| scale_note.chromatic_index() == pitch_class)?;
+
+        ScaleDegree::iter().nth(self.root_note.diatonic_interval_to(scale_note) as usize - 1)
+    }
+
+    /// Gets the interval up from the note at degree `from` to the note at
+    /// degree `to`, spelled by the notes' letters. When `to` is below `from`,
+    /// the interval wraps up to the next octave, so from the seventh degree of
+    /// C major to its second is B up to D.
+    ///
+    /// ```rust
+    /// use note_lib::{Scale, ScaleDegree, SimpleInterval};
+    ///
+    /// let c_major = Scale::c_major();
+    ///
+    /// assert_eq!(
+    ///     c_major.diatonic_interval_between(ScaleDegree::Second, ScaleDegree::Sixth),
+    ///     SimpleInterval::PerfectFifth
+    /// );
+    /// assert_eq!(
+    ///     c_major.diatonic_interval_between(ScaleDegree::Seventh, ScaleDegree::Second),
+    ///     SimpleInterval::MinorThird
+    /// );
+    /// ```
+    pub fn diatonic_interval_between(&self, from: ScaleDegree, to: ScaleDegree) -> SimpleInterval {
+        SimpleInterval::from_note_names(
+            self.mode.spelled_note_at_degree(self.root_note, from),
+            self.mode.spelled_note_at_degree(self.root_note, to),
+        )
+    }
+
+    /// Gets every note of this scale between `lo` and `hi` (inclusive), from
+    /// lowest to highest, across as many octaves as the range spans. Notes are
+    /// spelled by the scale, so a scale note that `lo` or `hi` spells
+    /// enharmonically is still included.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let notes = c_major.notes_in_range(
+    ///     Note::new(C, 3, NoteModifier::Natural),
+    ///     Note::new(C, 5, NoteModifier::Natural),
+    /// );
+    ///
+    /// assert_eq!(notes.len(), 15);
+    /// ```
+    pub fn notes_in_range(&self, lo: Note, hi: Note) -> Vec<Note> {
+        let hi_semitones = hi.to_semitones_from_c0();
+
+        let Ok(mut current) = self.snap_note_up(lo) else {
+            return vec![];
+        };
+
+        let mut notes = vec![];
+        while current.to_semitones_from_c0() <= hi_semitones {
+            notes.push(current);
+            if current.to_semitones_from_c0() == hi_semitones {
+                break;
+            }
+            current = match current.try_next_note_diatonic(self) {
+                Ok(next) => next,
+                Err(_) => break,
+            };
+        }
+
+        notes
+    }
+
+    /// Gets the MIDI note numbers of this scale from its root in `start_octave`
+    /// up to the root an octave above, where C4 is 60. Fails if any of the
+    /// notes is outside MIDI's range of 0 to 127.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// assert_eq!(c_major.midi_notes(4), Ok(vec![60, 62, 64, 65, 67, 69, 71, 72]));
+    /// ```
+    pub fn midi_notes(&self, start_octave: u8) -> Result<Vec<u8>, MidiRangeError> {
+        // MIDI note 0 is C-1, an octave below C0.
+        let root_midi_number =
+            (start_octave as Semitone + 1) * 12 + self.root_note.to_note(0).to_semitones_from_c0();
+
+        self.mode
+            .intervals()
+            .into_iter()
+            .chain(std::iter::once(SimpleInterval::PerfectOctave))
+            .map(|interval| {
+                let midi_number = root_midi_number + interval.semitones();
+                u8::try_from(midi_number)
+                    .ok()
+                    .filter(|midi_number| *midi_number <= 127)
+                    .ok_or(MidiRangeError(midi_number))
+            })
+            .collect()
+    }
+
+    /// Gets the next `count` notes of this scale strictly above `from`.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, A, B, C, D};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let notes = c_major.notes_above(Note::new(A, 4, NoteModifier::Natural), 3);
+    ///
+    /// assert_eq!(
+    ///     notes,
+    ///     vec![
+    ///         Note::new(B, 4, NoteModifier::Natural),
+    ///         Note::new(C, 5, NoteModifier::Natural),
+    ///         Note::new(D, 5, NoteModifier::Natural),
+    ///     ]
+    /// );
+    /// ```
+    pub fn notes_above(&self, from: Note, count: usize) -> Vec<Note> {
+        let mut current = from;
+
+        (0..count)
+            .map(|_| {
+                current = current.next_note_diatonic(self);
+                current
+            })
+            .collect()
+    }
+
+    /// Names the triad built on `degree` of this scale by its Roman numeral,
+    /// like "ii" or "vii°" in a major scale. See [`ScaleDegree::chord_numeral`].
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleDegree, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    ///
+    /// assert_eq!(c_major.degree_chord_name(ScaleDegree::Second), "ii");
+    /// assert_eq!(c_major.degree_chord_name(ScaleDegree::Seventh), "vii°");
+    /// ```
+    pub fn degree_chord_name(&self, degree: ScaleDegree) -> String {
+        match self.diatonic_chord_at_degree(degree).identify_quality() {
+            Some((_, quality)) => degree.chord_numeral(quality),
+            None => degree.to_string(),
+        }
+    }
+
+    /// Gets the triad built on `degree` from notes of this scale, spelled the
+    /// way the scale spells them. The root is placed in the fourth octave and
+    /// the third and fifth are stacked above it.
+    ///
+    /// Pentatonic and blues scales use the degrees and notes of their
+    /// [`ScaleMode::parent_mode`], so the chords of C major pentatonic are the
+    /// chords of C major.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Scale, ScaleDegree, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let chord = c_major.diatonic_chord_at_degree(ScaleDegree::Seventh);
+    ///
+    /// assert_eq!(chord.to_string(), "[B4, D5, F5]");
+    /// ```
+    pub fn diatonic_chord_at_degree(&self, degree: ScaleDegree) -> Chord {
+        self.stack_notes(degree, &[2, 4])
+    }
+
+    /// Builds a chord on the given degree of this scale, adding the scale
+    /// notes that are each of `steps` scale steps above it. For example, steps
+    /// of `[2, 4]` stack a triad. Modes without seven notes stack the notes of
+    /// their parent mode, so the steps count the same degrees as the root.
+    pub(crate) fn stack_notes(&self, degree: ScaleDegree, steps: &[usize]) -> Chord {
+        let parent = Scale::new(self.root_note, self.mode.parent_mode());
+        let root = parent
+            .mode
+            .spelled_note_at_degree(parent.root_note, degree)
+            .to_note(CHORD_ROOT_OCTAVE);
+        let notes_above = parent.notes_above(root, steps.iter().copied().max().unwrap_or(0));
+
+        let notes: Vec<Note> = std::iter::once(root)
+            .chain(steps.iter().map(|step| notes_above[step - 1]))
+            .collect();
+
+        Chord::new(notes)
+    }
+
+    /// Generates a melody of `length` notes that wanders this scale by one or
+    /// two steps at a time, starting from `start` and never straying more than
+    /// an octave from it. The steps are picked by a small pseudo-random number
+    /// generator, so the same `seed` always gives the same melody.
+    ///
+    /// If `start` is not in the scale, the melody starts on the next scale
+    /// note above it instead, or the one below it at the top of the range of
+    /// [`Octave`]. Steps that would leave that range turn around, the same as
+    /// steps that stray too far from `start`.
+    ///
+    /// ```rust
+    /// use note_lib::{AbstractNote, Note, NoteModifier, Scale, ScaleMode, C};
+    ///
+    /// let c_major = Scale::new(AbstractNote::from(C), ScaleMode::Ionian);
+    /// let start = Note::new(C, 4, NoteModifier::Natural);
+    ///
+    /// let melody = c_major.generate_melody(start, 8, 42);
+    /// assert_eq!(melody.len(), 8);
+    /// assert_eq!(melody[0], start);
+    /// assert_eq!(melody, c_major.generate_melody(start, 8, 42));
+    /// ```
+    pub fn generate_melody(&self, start: Note, length: usize, seed: u64) -> Vec<Note> {
+        let start_semitones = start.to_semitones_from_c0();
+        let mut current = self
+            .snap_note_up(start)
+            .or_else(|_| start.try_prev_note_diatonic(self))
+            .unwrap_or(start);
+
+        // A linear congruential generator, using Knuth's MMIX constants.
+        let mut state = seed;
+        let mut next_random = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            // The high bits of an LCG are much more random than the low ones.
+            state >> 33
+        };
+
+        let mut melody = Vec::with_capacity(length);
+        for _ in 0..length {
+            melody.push(current);
+
+            let steps = match next_random() % 4 {
+                0 => -2,
+                1 => -1,
+                2 => 1,
+                _ => 2,
+            };
+            // Turn around rather than leave the octave around the start, or
+            // the range of octaves.
+            current = match self.step_note(current, steps) {
+                Ok(next) if (next.to_semitones_from_c0() - start_semitones).abs() <= 12 => next,
+                _ => self.step_note(current, -steps).unwrap_or(current),
+            };
+        }
+
+        melody
+    }
+
+    /// Spells `note` the way this scale does, or moves it up to the next scale
+    /// note if it isn't in the scale. Fails if that note would be outside the
+    /// range of [`Octave`].
+    fn snap_note_up(&self, note: Note) -> Result<Note, NoteRangeError> {
+        if self.degree_of_note(AbstractNote::from(note)).is_some() {
+            Note::try_place_at_semitones(
+                self.spell_note(AbstractNote::from(note)),
+                note.to_semitones_from_c0(),
+            )
+        } else {
+            note.try_next_note_diatonic(self)
+        }
+    }
+
+    /// Moves `note` up (or down, for negative `steps`) by the given amount of
+    /// scale steps. Fails if a step would leave the range of [`Octave`].
+    fn step_note(&self, note: Note, steps: i32) -> Result<Note, NoteRangeError> {
+        (0..steps.abs()).try_fold(note, |note, _| {
+            if steps > 0 {
+                note.try_next_note_diatonic(self)
+            } else {
+                note.try_prev_note_diatonic(self)
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScaleParseError {
+    /// There was nothing to parse.
+    MissingRoot,
+    /// The root note couldn't be parsed.
+    InvalidRoot,
+    /// The mode isn't one that [`ScaleMode`] knows.
+    InvalidMode,
+    /// The mode is the start of more than one mode's name, like "mi".
+    AmbiguousMode,
+}
+
+/// Scales are equal when they have the same pitch classes, regardless of
+/// spelling or mode. This is pitch equality rather than structural equality,
+/// so C# major equals Db major, and C major equals A minor. Use
+/// [`Scale::structural_eq`] to compare the root and mode instead.
+impl PartialEq for Scale {
+    fn eq(&self, other: &Self) -> bool {
+        self.pitch_classes() == other.pitch_classes()
+    }
+}
+
+impl Eq for Scale {}
+
+/// Hashes the scale's pitch classes, to agree with its [`PartialEq`].
+impl Hash for Scale {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pitch_classes().hash(state);
+    }
+}
+
+/// Parses a scale from a root note followed by a mode, like "C major",
+/// "Bb Dorian" or "Am". A root on its own is a major scale. See
+/// [`ScaleMode::try_from`] for the mode names that are understood.
+///
+/// ```rust
+/// use note_lib::{AbstractNote, Scale, ScaleMode};
+///
+/// let scale: Scale = "G# Harmonic Minor".parse().unwrap();
+/// assert!(scale.structural_eq(&Scale::new(AbstractNote::try_from("G#").unwrap(), ScaleMode::HarmonicMinor)));
+/// ```
+impl FromStr for Scale {
+    type Err = ScaleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ScaleParseError::MissingRoot);
+        }
+
+        // The root is a letter followed by up to two modifier symbols.
+        let letter_length = s.chars().next().map(char::len_utf8).unwrap_or(0);
+        let modifier_length = s[letter_length..]
+            .chars()
+            .take(2)
+            .take_while(|c| matches!(c, '#' | 'b' | 'x'))
+            .count();
+        let root_length = letter_length + modifier_length;
+        let (root, mode) = s.split_at(root_length);
+
+        let root = AbstractNote::try_from(root).map_err(|_| ScaleParseError::InvalidRoot)?;
+        let mode = if mode.trim().is_empty() {
+            ScaleMode::Ionian
+        } else {
+            ScaleMode::try_from(mode)?
+        };
+
+        Ok(Scale::new(root, mode))
+    }
 }
 
 impl IntoIterator for Scale {
@@ -29,3 +839,764 @@ impl IntoIterator for Scale {
         ScaleNoteIter::new(self.root_note, self.mode)
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{ChordQuality, ModifierPreference, NoteModifier, Octave};
+
+    use super::*;
+
+    fn note(name: &str) -> AbstractNote {
+        AbstractNote::try_from(name).unwrap()
+    }
+
+    /// Asserts every pitch class, spelled with both sharps and flats, is spelled
+    /// as `expected` by the scale. A `None` entry means the pitch class is not in
+    /// the scale, so the input note should be returned unchanged.
+    fn assert_spells_all_pitch_classes(scale: &Scale, expected: [Option<&str>; 12]) {
+        for (pitch_class, expected) in expected.iter().enumerate() {
+            let interval = SimpleInterval::from_semitones(pitch_class as Semitone).interval;
+
+            for preference in [ModifierPreference::Sharp, ModifierPreference::Flat] {
+                let input = AbstractNote::from_interval_from_c(interval, preference);
+                let spelled = scale.spell_note(input);
+
+                match expected {
+                    Some(expected) => assert_eq!(spelled, note(expected)),
+                    None => assert_eq!(spelled, input),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn finds_degree_of_note() {
+        let d_dorian = Scale::new(note("D"), ScaleMode::Dorian);
+
+        assert_eq!(d_dorian.degree_of_note(note("D")), Some(ScaleDegree::First));
+        assert_eq!(d_dorian.degree_of_note(note("F")), Some(ScaleDegree::Third));
+        assert_eq!(d_dorian.degree_of_note(note("B")), Some(ScaleDegree::Sixth));
+        assert_eq!(
+            d_dorian.degree_of_note(note("B#")),
+            Some(ScaleDegree::Seventh)
+        );
+        assert_eq!(d_dorian.degree_of_note(note("Bb")), None);
+    }
+
+    #[test]
+    fn gets_notes_in_range() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        let names = |notes: Vec<Note>| notes.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        let notes = c_major.notes_in_range(note("C").to_note(3), note("C").to_note(5));
+        assert_eq!(
+            names(notes),
+            vec![
+                "C3", "D3", "E3", "F3", "G3", "A3", "B3", "C4", "D4", "E4", "F4", "G4", "A4", "B4",
+                "C5"
+            ]
+        );
+
+        // Bounds outside the scale are skipped over.
+        let notes = c_major.notes_in_range(note("C#").to_note(4), note("F#").to_note(4));
+        assert_eq!(names(notes), vec!["D4", "E4", "F4"]);
+
+        // Bounds that are enharmonic to scale notes use the scale's spelling.
+        let f_major = Scale::new(note("F"), ScaleMode::Ionian);
+        let notes = f_major.notes_in_range(note("A#").to_note(4), note("C").to_note(5));
+        assert_eq!(names(notes), vec!["Bb4", "C5"]);
+
+        assert!(c_major
+            .notes_in_range(note("C").to_note(5), note("C").to_note(4))
+            .is_empty());
+    }
+
+    #[test]
+    fn generates_melodies() {
+        let d_dorian = Scale::new(note("D"), ScaleMode::Dorian);
+        let start = note("D").to_note(4);
+
+        for seed in 0..20 {
+            let melody = d_dorian.generate_melody(start, 32, seed);
+
+            assert_eq!(melody.len(), 32);
+            assert_eq!(melody, d_dorian.generate_melody(start, 32, seed));
+            for melody_note in &melody {
+                assert!(d_dorian
+                    .degree_of_note(AbstractNote::from(*melody_note))
+                    .is_some());
+                assert!(
+                    (melody_note.to_semitones_from_c0() - start.to_semitones_from_c0()).abs() <= 24
+                );
+            }
+        }
+
+        assert_ne!(
+            d_dorian.generate_melody(start, 32, 1),
+            d_dorian.generate_melody(start, 32, 2)
+        );
+        assert!(d_dorian.generate_melody(start, 0, 1).is_empty());
+
+        // A start note outside the scale moves up onto the scale.
+        assert_eq!(
+            d_dorian.generate_melody(note("C#").to_note(4), 1, 1),
+            vec![note("D").to_note(4)]
+        );
+    }
+
+    #[test]
+    fn generates_melodies_at_the_ends_of_the_octave_range() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        let lowest = note("C").to_note(Octave::MIN).to_semitones_from_c0();
+        let highest = note("B").to_note(Octave::MAX).to_semitones_from_c0();
+
+        for start in [
+            note("A").to_note(Octave::MAX),
+            note("B").to_note(Octave::MAX),
+            note("C").to_note(Octave::MIN),
+            note("D").to_note(Octave::MIN),
+        ] {
+            for seed in 0..20 {
+                let melody = c_major.generate_melody(start, 32, seed);
+
+                assert_eq!(melody.len(), 32);
+                assert_eq!(melody[0], start);
+                for melody_note in &melody {
+                    let semitones = melody_note.to_semitones_from_c0();
+                    assert!((lowest..=highest).contains(&semitones), "{melody_note}");
+                }
+            }
+        }
+
+        // B#9 would be spelled C10 in C major, which is past the range, so the
+        // melody starts on the scale note below it.
+        assert_eq!(
+            c_major.generate_melody(note("B#").to_note(Octave::MAX), 1, 1),
+            vec![note("B").to_note(Octave::MAX)]
+        );
+    }
+
+    #[test]
+    fn splits_into_tetrachords() {
+        let names =
+            |notes: Vec<AbstractNote>| notes.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        let step_pattern = |notes: &[AbstractNote]| {
+            notes
+                .windows(2)
+                .map(|pair| {
+                    (pair[1].chromatic_index() as Semitone - pair[0].chromatic_index() as Semitone)
+                        .rem_euclid(12)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (lower, upper) = Scale::new(note("C"), ScaleMode::Ionian).tetrachords();
+        assert_eq!(names(lower.clone()), vec!["C", "D", "E", "F"]);
+        assert_eq!(names(upper.clone()), vec!["G", "A", "B", "C"]);
+        assert_eq!(step_pattern(&lower), vec![2, 2, 1]);
+        assert_eq!(step_pattern(&upper), vec![2, 2, 1]);
+
+        let (lower, upper) = Scale::new(note("A"), ScaleMode::HarmonicMinor).tetrachords();
+        assert_eq!(names(lower), vec!["A", "B", "C", "D"]);
+        assert_eq!(names(upper), vec!["E", "F", "G#", "A"]);
+    }
+
+    #[test]
+    fn finds_leading_tone() {
+        let has_leading_tone = |mode| Scale::new(note("C"), mode).has_leading_tone();
+
+        assert!(has_leading_tone(ScaleMode::Ionian));
+        assert!(has_leading_tone(ScaleMode::Lydian));
+        assert!(has_leading_tone(ScaleMode::HarmonicMinor));
+        assert!(!has_leading_tone(ScaleMode::Dorian));
+        assert!(!has_leading_tone(ScaleMode::Phrygian));
+        assert!(!has_leading_tone(ScaleMode::Mixolydian));
+        assert!(!has_leading_tone(ScaleMode::Aeolian));
+        assert!(!has_leading_tone(ScaleMode::Locrian));
+    }
+
+    #[test]
+    fn builds_diatonic_chord_at_degree() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        assert_eq!(
+            c_major
+                .diatonic_chord_at_degree(ScaleDegree::Second)
+                .to_string(),
+            "[D4, F4, A4]"
+        );
+        assert_eq!(
+            c_major
+                .diatonic_chord_at_degree(ScaleDegree::Seventh)
+                .to_string(),
+            "[B4, D5, F5]"
+        );
+
+        let e_flat_major = Scale::new(note("Eb"), ScaleMode::Ionian);
+        assert_eq!(
+            e_flat_major
+                .diatonic_chord_at_degree(ScaleDegree::Fourth)
+                .to_string(),
+            "[Ab4, C5, Eb5]"
+        );
+    }
+
+    #[test]
+    fn builds_parent_mode_chords_for_short_modes() {
+        let triads = |root: &str, mode| {
+            let scale = Scale::new(note(root), mode);
+            ScaleDegree::iter()
+                .filter(|degree| *degree != ScaleDegree::Octave)
+                .map(|degree| scale.diatonic_chord_at_degree(degree).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            triads("C", ScaleMode::PentatonicMajor),
+            triads("C", ScaleMode::Ionian)
+        );
+        assert_eq!(
+            triads("A", ScaleMode::PentatonicMinor),
+            triads("A", ScaleMode::Aeolian)
+        );
+        assert_eq!(
+            triads("C", ScaleMode::Blues),
+            triads("C", ScaleMode::Aeolian)
+        );
+
+        let c_pentatonic = Scale::new(note("C"), ScaleMode::PentatonicMajor);
+        assert_eq!(
+            c_pentatonic
+                .diatonic_chord_at_degree(ScaleDegree::First)
+                .to_string(),
+            "[C4, E4, G4]"
+        );
+        assert_eq!(
+            c_pentatonic
+                .diatonic_chord_at_degree(ScaleDegree::Fourth)
+                .to_string(),
+            "[F4, A4, C5]"
+        );
+        assert_eq!(
+            Scale::new(note("C"), ScaleMode::Blues)
+                .diatonic_chord_at_degree(ScaleDegree::First)
+                .to_string(),
+            "[C4, Eb4, G4]"
+        );
+
+        let names =
+            |notes: Vec<AbstractNote>| notes.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        let (lower, upper) = c_pentatonic.tetrachords();
+        assert_eq!(names(lower), vec!["C", "D", "E", "F"]);
+        assert_eq!(names(upper), vec!["G", "A", "B", "C"]);
+        let (lower, upper) = Scale::new(note("A"), ScaleMode::Blues).tetrachords();
+        assert_eq!(names(lower), vec!["A", "B", "C", "D"]);
+        assert_eq!(names(upper), vec!["E", "F", "G", "A"]);
+    }
+
+    #[test]
+    fn parses_scales() {
+        // Compare roots and modes, since scales compare equal by pitch.
+        let parse = |s: &str| s.parse::<Scale>().map(|scale| (scale.root(), scale.mode()));
+
+        assert_eq!(parse("C major"), Ok((note("C"), ScaleMode::Ionian)));
+        assert_eq!(parse("c MAJOR"), Ok((note("C"), ScaleMode::Ionian)));
+        assert_eq!(parse("C"), Ok((note("C"), ScaleMode::Ionian)));
+        assert_eq!(parse("CM"), Ok((note("C"), ScaleMode::Ionian)));
+        assert_eq!(parse("Am"), Ok((note("A"), ScaleMode::Aeolian)));
+        assert_eq!(parse("am"), Ok((note("A"), ScaleMode::Aeolian)));
+        assert_eq!(parse("A minor"), Ok((note("A"), ScaleMode::Aeolian)));
+        assert_eq!(
+            parse("A natural minor"),
+            Ok((note("A"), ScaleMode::Aeolian))
+        );
+        assert_eq!(parse("Bbm"), Ok((note("Bb"), ScaleMode::Aeolian)));
+        assert_eq!(parse("Bb Dorian"), Ok((note("Bb"), ScaleMode::Dorian)));
+        assert_eq!(parse("  F# lydian "), Ok((note("F#"), ScaleMode::Lydian)));
+        assert_eq!(
+            parse("G# Harmonic Minor"),
+            Ok((note("G#"), ScaleMode::HarmonicMinor))
+        );
+        assert_eq!(
+            parse("Ebbmixolydian"),
+            Ok((note("Ebb"), ScaleMode::Mixolydian))
+        );
+
+        assert_eq!(parse("C blues"), Ok((note("C"), ScaleMode::Blues)));
+
+        assert_eq!(parse(""), Err(ScaleParseError::MissingRoot));
+        assert_eq!(parse("   "), Err(ScaleParseError::MissingRoot));
+        assert_eq!(parse("H major"), Err(ScaleParseError::InvalidRoot));
+        assert_eq!(parse("C bebop"), Err(ScaleParseError::InvalidMode));
+        assert_eq!(parse("C mi"), Err(ScaleParseError::AmbiguousMode));
+    }
+
+    #[test]
+    fn names_scales() {
+        let name = |root: &str, mode| Scale::new(note(root), mode).common_scale_name();
+
+        assert_eq!(name("C", ScaleMode::Ionian), "C Major");
+        assert_eq!(name("A", ScaleMode::Aeolian), "A Natural Minor");
+        assert_eq!(name("G", ScaleMode::Dorian), "G Dorian");
+        assert_eq!(name("F", ScaleMode::Lydian), "F Lydian");
+        assert_eq!(name("Bb", ScaleMode::Mixolydian), "Bb Dominant");
+        assert_eq!(name("D", ScaleMode::LydianDominant), "D Acoustic");
+        assert_eq!(name("E", ScaleMode::HarmonicMinor), "E Harmonic Minor");
+    }
+
+    #[test]
+    fn spells_notes_in_sharp_key() {
+        let g_major = Scale::new(note("G"), ScaleMode::Ionian);
+        assert_eq!(g_major.spell_note(note("Gb")), note("F#"));
+
+        assert_spells_all_pitch_classes(
+            &g_major,
+            [
+                Some("C"),
+                None,
+                Some("D"),
+                None,
+                Some("E"),
+                None,
+                Some("F#"),
+                Some("G"),
+                None,
+                Some("A"),
+                None,
+                Some("B"),
+            ],
+        );
+    }
+
+    #[test]
+    fn spells_notes_in_flat_key() {
+        let f_major = Scale::new(note("F"), ScaleMode::Ionian);
+        assert_eq!(f_major.spell_note(note("A#")), note("Bb"));
+
+        assert_spells_all_pitch_classes(
+            &f_major,
+            [
+                Some("C"),
+                None,
+                Some("D"),
+                None,
+                Some("E"),
+                Some("F"),
+                None,
+                Some("G"),
+                None,
+                Some("A"),
+                Some("Bb"),
+                None,
+            ],
+        );
+    }
+
+    #[test]
+    fn compares_scales_by_pitch() {
+        use std::collections::HashSet;
+
+        let c_sharp_major = Scale::new(note("C#"), ScaleMode::Ionian);
+        let d_flat_major = Scale::new(note("Db"), ScaleMode::Ionian);
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        let a_minor = Scale::new(note("A"), ScaleMode::Aeolian);
+
+        assert_eq!(c_sharp_major, d_flat_major);
+        assert_ne!(c_major, Scale::new(note("G"), ScaleMode::Ionian));
+        assert_eq!(c_major, a_minor);
+        assert_ne!(a_minor, Scale::new(note("A"), ScaleMode::HarmonicMinor));
+
+        assert!(c_major.structural_eq(&Scale::new(note("C"), ScaleMode::Ionian)));
+        assert!(!c_major.structural_eq(&a_minor));
+        assert!(!c_sharp_major.structural_eq(&d_flat_major));
+
+        let scales: HashSet<Scale> = [c_sharp_major, d_flat_major, c_major, a_minor]
+            .into_iter()
+            .collect();
+        assert_eq!(scales.len(), 2);
+    }
+
+    #[test]
+    fn analyzes_chords_against_scales() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+
+        let g7 = ChordQuality::Dominant7th.to_chord(note("G").to_note(3));
+        let analysis = c_major.analyze_chord(&g7);
+        assert_eq!(analysis.chord_tones, g7.notes());
+        assert_eq!(analysis.tensions, vec![note("A"), note("E")]);
+        assert_eq!(analysis.avoid_notes, vec![note("C")]);
+
+        // The 13th of a Dorian minor 7th chord is a tritone above its third.
+        let dm7 = ChordQuality::Minor7th.to_chord(note("D").to_note(3));
+        let analysis = c_major.analyze_chord(&dm7);
+        assert_eq!(analysis.tensions, vec![note("E"), note("G")]);
+        assert_eq!(analysis.avoid_notes, vec![note("B")]);
+
+        // The major 7th of a triad is neither a tension nor an avoid note.
+        let c = ChordQuality::Major.to_chord(note("C").to_note(4));
+        let analysis = c_major.analyze_chord(&c);
+        assert_eq!(analysis.tensions, vec![note("D"), note("A")]);
+        assert_eq!(analysis.avoid_notes, vec![note("F")]);
+
+        assert_eq!(
+            c_major.analyze_chord(&Chord::default()),
+            ChordScaleAnalysis::default()
+        );
+    }
+
+    #[test]
+    fn gets_midi_notes() {
+        for mode in ScaleMode::iter() {
+            for octave in 0..=8 {
+                let midi_notes = Scale::new(note("C"), mode).midi_notes(octave).unwrap();
+                let root = (octave + 1) * 12;
+
+                assert_eq!(midi_notes.len(), mode.intervals().len() + 1);
+                assert_eq!(midi_notes.first(), Some(&root));
+                assert_eq!(midi_notes.last(), Some(&(root + 12)));
+                assert!(midi_notes.windows(2).all(|pair| pair[0] < pair[1]));
+            }
+        }
+
+        let a_minor = Scale::new(note("A"), ScaleMode::Aeolian);
+        assert_eq!(
+            a_minor.midi_notes(3),
+            Ok(vec![57, 59, 60, 62, 64, 65, 67, 69])
+        );
+
+        // Cb3 sounds the same as B2.
+        let c_flat_major = Scale::new(note("Cb"), ScaleMode::Ionian);
+        assert_eq!(c_flat_major.midi_notes(3).unwrap()[0], 47);
+
+        assert_eq!(
+            Scale::new(note("C"), ScaleMode::Ionian).midi_notes(9),
+            Err(MidiRangeError(129))
+        );
+    }
+
+    #[test]
+    fn pentatonic_subset_is_in_parent_scale() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        let pentatonic = c_major.pentatonic_subset().unwrap();
+
+        assert_eq!(pentatonic.pitch_classes().len(), 5);
+        assert!(pentatonic
+            .pitch_classes()
+            .is_subset(&c_major.pitch_classes()));
+
+        let a_minor = Scale::new(note("A"), ScaleMode::Aeolian);
+        assert_eq!(a_minor.pentatonic_subset(), Some(pentatonic));
+        assert_eq!(
+            Scale::new(note("D"), ScaleMode::Dorian).pentatonic_subset(),
+            None
+        );
+    }
+
+    #[test]
+    fn blues_subset_adds_blue_note() {
+        let a_minor = Scale::new(note("A"), ScaleMode::Aeolian);
+        let blues = a_minor.blues_subset().unwrap();
+        let notes: Vec<String> = blues.all_notes().iter().map(|n| n.to_string()).collect();
+
+        assert_eq!(notes, vec!["A", "C", "D", "Eb", "E", "G"]);
+        assert!(Scale::new(note("C"), ScaleMode::Ionian)
+            .blues_subset()
+            .unwrap()
+            .structural_eq(&blues));
+        assert_eq!(
+            Scale::new(note("C"), ScaleMode::Lydian).blues_subset(),
+            None
+        );
+    }
+
+    #[test]
+    fn steps_through_pentatonic_scale() {
+        let c_pentatonic = Scale::new(note("C"), ScaleMode::PentatonicMajor);
+
+        assert_eq!(
+            c_pentatonic.degree_of_note(note("G")),
+            Some(ScaleDegree::Fifth)
+        );
+        assert_eq!(c_pentatonic.degree_of_note(note("F")), None);
+        assert_eq!(
+            note("E").next_note_diatonic(note("C"), ScaleMode::PentatonicMajor),
+            note("G")
+        );
+        assert_eq!(c_pentatonic.midi_notes(4), Ok(vec![60, 62, 64, 67, 69, 72]));
+    }
+
+    #[test]
+    fn finds_leading_tone_or_subtonic() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        assert_eq!(c_major.leading_tone(), Some(note("B")));
+        assert_eq!(c_major.subtonic(), None);
+
+        let c_minor = Scale::new(note("C"), ScaleMode::Aeolian);
+        assert_eq!(c_minor.leading_tone(), None);
+        assert_eq!(c_minor.subtonic(), Some(note("Bb")));
+
+        assert_eq!(
+            Scale::new(note("A"), ScaleMode::HarmonicMinor).leading_tone(),
+            Some(note("G#"))
+        );
+
+        // Every seven note scale has exactly one kind of seventh.
+        for mode in ScaleMode::iter().filter(|mode| mode.intervals().len() == 7) {
+            let scale = Scale::new(note("D"), mode);
+            assert!(
+                scale.leading_tone().is_some() != scale.subtonic().is_some(),
+                "{:?}",
+                mode
+            );
+            assert_eq!(scale.leading_tone().is_some(), scale.has_leading_tone());
+        }
+
+        assert_eq!(
+            Scale::new(note("C"), ScaleMode::PentatonicMajor).leading_tone(),
+            None
+        );
+    }
+
+    #[test]
+    fn names_degree_chords() {
+        let names = |scale: Scale| {
+            ScaleDegree::iter()
+                .filter(|degree| *degree != ScaleDegree::Octave)
+                .map(|degree| scale.degree_chord_name(degree))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(Scale::new(note("C"), ScaleMode::Ionian)),
+            vec!["I", "ii", "iii", "IV", "V", "vi", "vii°"]
+        );
+        assert_eq!(
+            names(Scale::new(note("A"), ScaleMode::HarmonicMinor)),
+            vec!["i", "ii°", "III+", "iv", "V", "VI", "vii°"]
+        );
+    }
+
+    #[test]
+    fn compares_scale_notes() {
+        let c_major = Scale::new(note("C"), ScaleMode::Ionian);
+        let g_major = Scale::new(note("G"), ScaleMode::Ionian);
+
+        assert_eq!(
+            c_major.common_tones(&g_major),
+            ["C", "D", "E", "G", "A", "B"].map(note)
+        );
+        assert_eq!(c_major.difference(&g_major), vec![note("F")]);
+        assert_eq!(g_major.difference(&c_major), vec![note("F#")]);
+
+        // Spellings come from the scale being asked, even when the other
+        // scale spells the pitch class differently.
+        let db_major = Scale::new(note("Db"), ScaleMode::Ionian);
+        let b_major = Scale::new(note("B"), ScaleMode::Ionian);
+        assert_eq!(
+            db_major.common_tones(&b_major),
+            ["Db", "Eb", "Gb", "Ab", "Bb"].map(note)
+        );
+
+        for (scale, other) in [(c_major, g_major), (db_major, b_major)] {
+            let mut notes = scale.common_tones(&other);
+            notes.extend(scale.difference(&other));
+            let mut expected = scale.all_notes();
+
+            let key = |note: &AbstractNote| note.chromatic_index();
+            notes.sort_by_key(key);
+            expected.sort_by_key(key);
+            assert_eq!(notes, expected);
+        }
+    }
+
+    #[test]
+    fn spells_chords_in_scale() {
+        let g_major = Scale::new(note("G"), ScaleMode::Ionian);
+        let chord =
+            |names: &[&str]| Chord::new(names.iter().map(|name| note(name).to_note(4)).collect());
+
+        // Already spelled by the scale.
+        assert_eq!(
+            g_major.spell_chord(&chord(&["D", "F#", "A"])),
+            chord(&["D", "F#", "A"])
+        );
+        // Gb is F# and Fb is E in G major.
+        assert_eq!(
+            g_major.spell_chord(&chord(&["C", "Gb", "Fb"])),
+            chord(&["C", "F#", "E"])
+        );
+        // Bb isn't in G major, so it is spelled with sharps like the key.
+        assert_eq!(
+            g_major.spell_chord(&chord(&["G", "Bb", "D"])),
+            chord(&["G", "A#", "D"])
+        );
+    }
+
+    #[test]
+    fn builds_well_known_scales() {
+        let c_major = Scale::c_major();
+        assert_eq!(c_major.root(), note("C"));
+        assert_eq!(c_major.mode(), ScaleMode::Ionian);
+
+        let a_minor = Scale::a_minor();
+        assert_eq!(a_minor.root(), note("A"));
+        assert_eq!(a_minor.mode(), ScaleMode::Aeolian);
+
+        for scale in [c_major, a_minor] {
+            assert!(scale
+                .all_notes()
+                .iter()
+                .all(|note| note.modifier == NoteModifier::Natural));
+        }
+    }
+
+    #[test]
+    fn measures_intervals_between_degrees() {
+        use SimpleInterval::*;
+
+        // Up from each degree of the major scale to each degree, in order.
+        let major = [
+            [
+                PerfectUnison,
+                MajorSecond,
+                MajorThird,
+                PerfectFourth,
+                PerfectFifth,
+                MajorSixth,
+                MajorSeventh,
+            ],
+            [
+                MinorSeventh,
+                PerfectUnison,
+                MajorSecond,
+                MinorThird,
+                PerfectFourth,
+                PerfectFifth,
+                MajorSixth,
+            ],
+            [
+                MinorSixth,
+                MinorSeventh,
+                PerfectUnison,
+                MinorSecond,
+                MinorThird,
+                PerfectFourth,
+                PerfectFifth,
+            ],
+            [
+                PerfectFifth,
+                MajorSixth,
+                MajorSeventh,
+                PerfectUnison,
+                MajorSecond,
+                MajorThird,
+                AugmentedFourth,
+            ],
+            [
+                PerfectFourth,
+                PerfectFifth,
+                MajorSixth,
+                MinorSeventh,
+                PerfectUnison,
+                MajorSecond,
+                MajorThird,
+            ],
+            [
+                MinorThird,
+                PerfectFourth,
+                PerfectFifth,
+                MinorSixth,
+                MinorSeventh,
+                PerfectUnison,
+                MajorSecond,
+            ],
+            [
+                MinorSecond,
+                MinorThird,
+                PerfectFourth,
+                DiminishedFifth,
+                MinorSixth,
+                MinorSeventh,
+                PerfectUnison,
+            ],
+        ];
+        let degrees: Vec<ScaleDegree> = ScaleDegree::iter()
+            .filter(|degree| *degree != ScaleDegree::Octave)
+            .collect();
+
+        for root in ["C", "Eb", "F#"] {
+            let ionian = Scale::new(note(root), ScaleMode::Ionian);
+            let aeolian = Scale::new(note(root), ScaleMode::Aeolian);
+
+            for (i, from) in degrees.iter().enumerate() {
+                for (j, to) in degrees.iter().enumerate() {
+                    assert_eq!(
+                        ionian.diatonic_interval_between(*from, *to),
+                        major[i][j],
+                        "{} {:?} to {:?}",
+                        root,
+                        from,
+                        to
+                    );
+
+                    // Aeolian is the major scale started from its sixth.
+                    assert_eq!(
+                        aeolian.diatonic_interval_between(*from, *to),
+                        major[(i + 5) % 7][(j + 5) % 7],
+                        "{} {:?} to {:?}",
+                        root,
+                        from,
+                        to
+                    );
+                }
+            }
+        }
+
+        assert_eq!(
+            Scale::c_major().diatonic_interval_between(ScaleDegree::Octave, ScaleDegree::Fifth),
+            PerfectFifth
+        );
+    }
+
+    #[test]
+    fn rotates_modes_by_degree() {
+        let modes_of_major = [
+            (ScaleDegree::First, "C", ScaleMode::Ionian),
+            (ScaleDegree::Second, "D", ScaleMode::Dorian),
+            (ScaleDegree::Third, "E", ScaleMode::Phrygian),
+            (ScaleDegree::Fourth, "F", ScaleMode::Lydian),
+            (ScaleDegree::Fifth, "G", ScaleMode::Mixolydian),
+            (ScaleDegree::Sixth, "A", ScaleMode::Aeolian),
+            (ScaleDegree::Seventh, "B", ScaleMode::Locrian),
+            (ScaleDegree::Octave, "C", ScaleMode::Ionian),
+        ];
+
+        let c_major = Scale::c_major();
+        for (degree, root, mode) in modes_of_major {
+            assert_eq!(c_major.mode_at_degree(degree), Some(mode), "{:?}", degree);
+
+            let rotated = c_major.starting_at_degree(degree).unwrap();
+            assert_eq!(rotated.root(), note(root));
+            assert_eq!(rotated.mode(), mode);
+            assert_eq!(rotated, c_major);
+        }
+
+        // Rotating a mode goes on from where it starts.
+        let d_dorian = Scale::new(note("D"), ScaleMode::Dorian);
+        assert_eq!(
+            d_dorian.mode_at_degree(ScaleDegree::Second),
+            Some(ScaleMode::Phrygian)
+        );
+        assert_eq!(
+            d_dorian.mode_at_degree(ScaleDegree::Seventh),
+            Some(ScaleMode::Ionian)
+        );
+
+        let a_harmonic_minor = Scale::new(note("A"), ScaleMode::HarmonicMinor);
+        assert_eq!(a_harmonic_minor.mode_at_degree(ScaleDegree::Fifth), None);
+        assert_eq!(
+            a_harmonic_minor.starting_at_degree(ScaleDegree::Fifth),
+            None
+        );
+    }
+}
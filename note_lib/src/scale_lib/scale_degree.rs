@@ -1,3 +1,9 @@
+use std::fmt::Display;
+
+use strum::IntoEnumIterator;
+
+use crate::ChordQuality;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum_macros::EnumIter)]
 pub enum ScaleDegree {
     First,
@@ -9,3 +15,107 @@ pub enum ScaleDegree {
     Seventh,
     Octave,
 }
+
+impl ScaleDegree {
+    /// Moves this degree by `steps` scale steps, wrapping around the seven
+    /// degrees of the scale. The octave is treated as the first degree, so
+    /// moving never produces [`ScaleDegree::Octave`].
+    ///
+    /// ```rust
+    /// use note_lib::ScaleDegree;
+    ///
+    /// assert_eq!(ScaleDegree::Third.offset_by(2), ScaleDegree::Fifth);
+    /// assert_eq!(ScaleDegree::Seventh.offset_by(1), ScaleDegree::First);
+    /// assert_eq!(ScaleDegree::First.offset_by(-1), ScaleDegree::Seventh);
+    /// ```
+    pub fn offset_by(&self, steps: i32) -> ScaleDegree {
+        let degrees: Vec<ScaleDegree> = ScaleDegree::iter()
+            .filter(|degree| *degree != ScaleDegree::Octave)
+            .collect();
+
+        let index = degrees
+            .iter()
+            .position(|degree| degree == self)
+            .unwrap_or(0) as i32;
+
+        degrees[(index + steps).rem_euclid(degrees.len() as i32) as usize]
+    }
+
+    /// Formats this degree as the Roman numeral of a chord with `quality`
+    /// built on it. Chords in the minor and diminished families are lower
+    /// case and the rest are upper case, followed by a symbol for the
+    /// quality, like `vii°`, `III+`, `V7` or `IIMaj7`.
+    ///
+    /// ```rust
+    /// use note_lib::{ChordQuality, ScaleDegree};
+    ///
+    /// assert_eq!(ScaleDegree::Second.chord_numeral(ChordQuality::Minor), "ii");
+    /// assert_eq!(ScaleDegree::Fifth.chord_numeral(ChordQuality::Dominant7th), "V7");
+    /// assert_eq!(ScaleDegree::Second.chord_numeral(ChordQuality::Major7th), "IIMaj7");
+    /// assert_eq!(ScaleDegree::First.chord_numeral(ChordQuality::Neapolitan), "bII");
+    /// assert_eq!(ScaleDegree::Fifth.chord_numeral(ChordQuality::Neapolitan), "bII/V");
+    /// ```
+    ///
+    /// The Neapolitan is built from the tonic rather than its own root, so it
+    /// is written `bII` on the first degree, and as an applied chord like
+    /// `bII/V` on the others.
+    pub fn chord_numeral(&self, quality: ChordQuality) -> String {
+        let numeral = if quality.is_category_minor() || quality.is_diminished() {
+            self.to_string().to_lowercase()
+        } else {
+            self.to_string()
+        };
+
+        let symbol = match quality {
+            ChordQuality::Major | ChordQuality::Minor => "",
+            ChordQuality::Major6th | ChordQuality::Minor6th => "6",
+            ChordQuality::Major7th | ChordQuality::MinorMajor7th => "Maj7",
+            ChordQuality::Major9th => "Maj9",
+            ChordQuality::Major11th => "Maj11",
+            ChordQuality::Major13th => "Maj13",
+            ChordQuality::Minor7th | ChordQuality::Dominant7th => "7",
+            ChordQuality::Minor9th => "9",
+            ChordQuality::Minor11th => "11",
+            ChordQuality::Minor13th => "13",
+            ChordQuality::MinorMajor7thFlat13th => "Maj7b13",
+            ChordQuality::Augmented => "+",
+            ChordQuality::Augmented7th => "+7",
+            ChordQuality::AugmentedMajor7th => "+Maj7",
+            ChordQuality::Diminished => "°",
+            ChordQuality::Diminished7th => "°7",
+            ChordQuality::Suspended2nd => "sus2",
+            ChordQuality::Suspended4th => "sus4",
+            ChordQuality::Italian6 => "It+6",
+            ChordQuality::French6 => "Fr+6",
+            ChordQuality::German6 => "Ger+6",
+            ChordQuality::Quartal3 => "q4-3",
+            ChordQuality::Quartal4 => "q4-4",
+            ChordQuality::Neapolitan => {
+                return match self {
+                    ScaleDegree::First | ScaleDegree::Octave => quality.short_name().to_string(),
+                    _ => format!("{}/{}", quality.short_name(), self),
+                };
+            }
+        };
+
+        format!("{}{}", numeral, symbol)
+    }
+}
+
+/// Displays the degree as an upper case Roman numeral, like `IV`.
+impl Display for ScaleDegree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let numeral = match self {
+            ScaleDegree::First => "I",
+            ScaleDegree::Second => "II",
+            ScaleDegree::Third => "III",
+            ScaleDegree::Fourth => "IV",
+            ScaleDegree::Fifth => "V",
+            ScaleDegree::Sixth => "VI",
+            ScaleDegree::Seventh => "VII",
+            ScaleDegree::Octave => "VIII",
+        };
+
+        write!(f, "{}", numeral)
+    }
+}
@@ -1,9 +1,23 @@
-use super::ScaleDegree;
+use strum::IntoEnumIterator;
+
+use super::{ScaleDegree, ScaleParseError};
 use crate::{AbstractNote, SimpleInterval};
 
+/// The seven modes of the major scale, in the order of the degree of the major
+/// scale they start on.
+pub(crate) const CHURCH_MODES: [ScaleMode; 7] = [
+    ScaleMode::Ionian,
+    ScaleMode::Dorian,
+    ScaleMode::Phrygian,
+    ScaleMode::Lydian,
+    ScaleMode::Mixolydian,
+    ScaleMode::Aeolian,
+    ScaleMode::Locrian,
+];
+
 /// ScaleMode represents the various patterns of notes that can be created
 /// from a root note.
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, strum_macros::EnumIter)]
 pub enum ScaleMode {
     /// Ionian represents the diatonic major scale.
     /// https://en.wikipedia.org/wiki/Mode_(music)#Ionian_(I)
@@ -49,6 +63,41 @@ pub enum ScaleMode {
     /// Interval pattern from root:
     /// P1 | m2 | m3 | P4 | d5 | m6 | m7 | P8
     Locrian,
+    /// Harmonic minor is the (Aeolian) natural minor scale with a major seventh,
+    /// which gives minor keys a leading tone.
+    /// https://en.wikipedia.org/wiki/Minor_scale#Harmonic_minor_scale
+    ///
+    /// Interval pattern from root:
+    /// P1 | M2 | m3 | P4 | P5 | m6 | M7 | P8
+    HarmonicMinor,
+    /// Lydian dominant is the (Lydian) scale with a minor seventh, or the
+    /// (Mixolydian) scale with an augmented fourth. It is also called the
+    /// acoustic scale. https://en.wikipedia.org/wiki/Acoustic_scale
+    ///
+    /// Interval pattern from root:
+    /// P1 | M2 | M3 | A4 | P5 | M6 | m7 | P8
+    LydianDominant,
+    /// The major pentatonic scale is the (Ionian) major scale without its
+    /// fourth and seventh, leaving no half steps.
+    /// https://en.wikipedia.org/wiki/Pentatonic_scale#Major_pentatonic_scale
+    ///
+    /// Interval pattern from root:
+    /// P1 | M2 | M3 | P5 | M6 | P8
+    PentatonicMajor,
+    /// The minor pentatonic scale is the (Aeolian) natural minor scale without
+    /// its second and sixth. It has the same notes as the major pentatonic
+    /// scale a minor third above.
+    /// https://en.wikipedia.org/wiki/Pentatonic_scale#Minor_pentatonic_scale
+    ///
+    /// Interval pattern from root:
+    /// P1 | m3 | P4 | P5 | m7 | P8
+    PentatonicMinor,
+    /// The blues scale is the (PentatonicMinor) scale with an added diminished
+    /// fifth, the "blue note". https://en.wikipedia.org/wiki/Blues_scale
+    ///
+    /// Interval pattern from root:
+    /// P1 | m3 | P4 | d5 | P5 | m7 | P8
+    Blues,
 }
 
 fn ionian_intervals(degree: ScaleDegree) -> SimpleInterval {
@@ -142,6 +191,32 @@ fn locrian_intervals(degree: ScaleDegree) -> SimpleInterval {
     }
 }
 
+fn harmonic_minor_intervals(degree: ScaleDegree) -> SimpleInterval {
+    match degree {
+        ScaleDegree::First => SimpleInterval::PerfectUnison,
+        ScaleDegree::Second => SimpleInterval::MajorSecond,
+        ScaleDegree::Third => SimpleInterval::MinorThird,
+        ScaleDegree::Fourth => SimpleInterval::PerfectFourth,
+        ScaleDegree::Fifth => SimpleInterval::PerfectFifth,
+        ScaleDegree::Sixth => SimpleInterval::MinorSixth,
+        ScaleDegree::Seventh => SimpleInterval::MajorSeventh,
+        ScaleDegree::Octave => SimpleInterval::PerfectOctave,
+    }
+}
+
+fn lydian_dominant_intervals(degree: ScaleDegree) -> SimpleInterval {
+    match degree {
+        ScaleDegree::First => SimpleInterval::PerfectUnison,
+        ScaleDegree::Second => SimpleInterval::MajorSecond,
+        ScaleDegree::Third => SimpleInterval::MajorThird,
+        ScaleDegree::Fourth => SimpleInterval::AugmentedFourth,
+        ScaleDegree::Fifth => SimpleInterval::PerfectFifth,
+        ScaleDegree::Sixth => SimpleInterval::MajorSixth,
+        ScaleDegree::Seventh => SimpleInterval::MinorSeventh,
+        ScaleDegree::Octave => SimpleInterval::PerfectOctave,
+    }
+}
+
 impl ScaleMode {
     /// Get the interval of the degree of the scale.
     ///
@@ -158,6 +233,10 @@ impl ScaleMode {
     /// assert_eq!(interval_at_three, SimpleInterval::MajorThird);
     /// ```
     ///
+    ///
+    /// Modes with fewer or more than seven notes, like [`ScaleMode::PentatonicMajor`],
+    /// use the degrees of their [`ScaleMode::parent_mode`]. Use [`ScaleMode::intervals`]
+    /// to get the notes the mode actually has.
     pub fn interval_at_degree(&self, degree: ScaleDegree) -> SimpleInterval {
         match self {
             ScaleMode::Ionian => ionian_intervals(degree),
@@ -167,6 +246,139 @@ impl ScaleMode {
             ScaleMode::Mixolydian => mixolydian_intervals(degree),
             ScaleMode::Aeolian => aeolian_intervals(degree),
             ScaleMode::Locrian => locrian_intervals(degree),
+            ScaleMode::HarmonicMinor => harmonic_minor_intervals(degree),
+            ScaleMode::LydianDominant => lydian_dominant_intervals(degree),
+            ScaleMode::PentatonicMajor | ScaleMode::PentatonicMinor | ScaleMode::Blues => {
+                self.parent_mode().interval_at_degree(degree)
+            }
+        }
+    }
+
+    /// Gets the intervals of every note of this mode from the root, up to but
+    /// not including the octave.
+    ///
+    /// ```rust
+    /// use note_lib::{ScaleMode, SimpleInterval};
+    ///
+    /// assert_eq!(ScaleMode::Ionian.intervals().len(), 7);
+    /// assert_eq!(
+    ///     ScaleMode::PentatonicMinor.intervals(),
+    ///     vec![
+    ///         SimpleInterval::PerfectUnison,
+    ///         SimpleInterval::MinorThird,
+    ///         SimpleInterval::PerfectFourth,
+    ///         SimpleInterval::PerfectFifth,
+    ///         SimpleInterval::MinorSeventh,
+    ///     ]
+    /// );
+    /// ```
+    pub fn intervals(&self) -> Vec<SimpleInterval> {
+        match self {
+            ScaleMode::PentatonicMajor => vec![
+                SimpleInterval::PerfectUnison,
+                SimpleInterval::MajorSecond,
+                SimpleInterval::MajorThird,
+                SimpleInterval::PerfectFifth,
+                SimpleInterval::MajorSixth,
+            ],
+            ScaleMode::PentatonicMinor => vec![
+                SimpleInterval::PerfectUnison,
+                SimpleInterval::MinorThird,
+                SimpleInterval::PerfectFourth,
+                SimpleInterval::PerfectFifth,
+                SimpleInterval::MinorSeventh,
+            ],
+            ScaleMode::Blues => vec![
+                SimpleInterval::PerfectUnison,
+                SimpleInterval::MinorThird,
+                SimpleInterval::PerfectFourth,
+                SimpleInterval::DiminishedFifth,
+                SimpleInterval::PerfectFifth,
+                SimpleInterval::MinorSeventh,
+            ],
+            _ => ScaleDegree::iter()
+                .filter(|degree| *degree != ScaleDegree::Octave)
+                .map(|degree| self.interval_at_degree(degree))
+                .collect(),
+        }
+    }
+
+    /// Gets the seven note mode this mode is taken from. The pentatonic modes
+    /// leave notes out of the major and natural minor scales, and the blues
+    /// scale adds one to the natural minor scale. Seven note modes are their
+    /// own parent.
+    ///
+    /// ```rust
+    /// use note_lib::ScaleMode;
+    ///
+    /// assert_eq!(ScaleMode::PentatonicMajor.parent_mode(), ScaleMode::Ionian);
+    /// assert_eq!(ScaleMode::Dorian.parent_mode(), ScaleMode::Dorian);
+    /// ```
+    pub fn parent_mode(&self) -> ScaleMode {
+        match self {
+            ScaleMode::PentatonicMajor => ScaleMode::Ionian,
+            ScaleMode::PentatonicMinor | ScaleMode::Blues => ScaleMode::Aeolian,
+            mode => *mode,
+        }
+    }
+
+    /// Whether this is one of the seven church modes, the modes of the major
+    /// scale from Ionian to Locrian.
+    ///
+    /// ```rust
+    /// use note_lib::ScaleMode;
+    ///
+    /// assert!(ScaleMode::Phrygian.is_church_mode());
+    /// assert!(!ScaleMode::HarmonicMinor.is_church_mode());
+    /// ```
+    pub fn is_church_mode(&self) -> bool {
+        CHURCH_MODES.contains(self)
+    }
+
+    /// Gets the name of this mode, like "Dorian" or "Harmonic Minor".
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScaleMode::Ionian => "Ionian",
+            ScaleMode::Dorian => "Dorian",
+            ScaleMode::Phrygian => "Phrygian",
+            ScaleMode::Lydian => "Lydian",
+            ScaleMode::Mixolydian => "Mixolydian",
+            ScaleMode::Aeolian => "Aeolian",
+            ScaleMode::Locrian => "Locrian",
+            ScaleMode::HarmonicMinor => "Harmonic Minor",
+            ScaleMode::LydianDominant => "Lydian Dominant",
+            ScaleMode::PentatonicMajor => "Major Pentatonic",
+            ScaleMode::PentatonicMinor => "Minor Pentatonic",
+            ScaleMode::Blues => "Blues",
+        }
+    }
+
+    /// Gets the everyday name of this mode, for the modes that are better
+    /// known by one. Ionian is the major scale and Aeolian is the natural
+    /// minor scale, for example. Modes without a commonly agreed name, like
+    /// Dorian, return `None`.
+    ///
+    /// ```rust
+    /// use note_lib::ScaleMode;
+    ///
+    /// assert_eq!(ScaleMode::Ionian.common_name(), Some("Major"));
+    /// assert_eq!(ScaleMode::Dorian.common_name(), None);
+    /// ```
+    pub fn common_name(&self) -> Option<&'static str> {
+        match self {
+            ScaleMode::Ionian => Some("Major"),
+            ScaleMode::Aeolian => Some("Natural Minor"),
+            // Mostly in jazz, where it's the scale played over dominant chords.
+            ScaleMode::Mixolydian => Some("Dominant"),
+            ScaleMode::LydianDominant => Some("Acoustic"),
+            ScaleMode::HarmonicMinor => Some("Harmonic Minor"),
+            ScaleMode::Dorian
+            | ScaleMode::Phrygian
+            | ScaleMode::Lydian
+            | ScaleMode::Locrian
+            | ScaleMode::PentatonicMajor
+            | ScaleMode::PentatonicMinor
+            | ScaleMode::Blues => None,
         }
     }
 
@@ -186,6 +398,97 @@ impl ScaleMode {
         let interval = self.interval_at_degree(degree);
         root.add_interval(interval)
     }
+
+    /// Gets the abstract note at the given degree, spelled the way the key
+    /// signature of this scale would spell it. Unlike [`ScaleMode::note_at_degree`],
+    /// which only tracks semitones, every degree is given its own letter name.
+    /// For example, the fourth degree of F Ionian is Bb, not A#.
+    ///
+    /// If the correct letter would need more than a double sharp or double flat,
+    /// the semitone-based spelling from [`ScaleMode::note_at_degree`] is used instead.
+    ///
+    /// ```rust
+    /// use note_lib::{ScaleDegree, ScaleMode, AbstractNote};
+    ///
+    /// let mode = ScaleMode::Ionian;
+    /// let root = AbstractNote::try_from("F").unwrap();
+    ///
+    /// let note_at_degree = mode.spelled_note_at_degree(root, ScaleDegree::Fourth);
+    ///
+    /// assert_eq!(note_at_degree, AbstractNote::try_from("Bb").unwrap());
+    /// ```
+    pub fn spelled_note_at_degree(&self, root: AbstractNote, degree: ScaleDegree) -> AbstractNote {
+        root.spelled_add_interval(self.interval_at_degree(degree))
+    }
+}
+
+/// The names a [`ScaleMode`] can be parsed from, in lower case.
+const SCALE_MODE_NAMES: [(&str, ScaleMode); 18] = [
+    ("major", ScaleMode::Ionian),
+    ("ionian", ScaleMode::Ionian),
+    ("dorian", ScaleMode::Dorian),
+    ("phrygian", ScaleMode::Phrygian),
+    ("lydian", ScaleMode::Lydian),
+    ("mixolydian", ScaleMode::Mixolydian),
+    ("minor", ScaleMode::Aeolian),
+    ("natural minor", ScaleMode::Aeolian),
+    ("aeolian", ScaleMode::Aeolian),
+    ("locrian", ScaleMode::Locrian),
+    ("harmonic minor", ScaleMode::HarmonicMinor),
+    ("lydian dominant", ScaleMode::LydianDominant),
+    ("acoustic", ScaleMode::LydianDominant),
+    ("major pentatonic", ScaleMode::PentatonicMajor),
+    ("pentatonic major", ScaleMode::PentatonicMajor),
+    ("minor pentatonic", ScaleMode::PentatonicMinor),
+    ("pentatonic minor", ScaleMode::PentatonicMinor),
+    ("blues", ScaleMode::Blues),
+];
+
+/// Parses a mode from its name, like "Dorian" or "natural minor", ignoring
+/// case. "M" and "maj" are major and "m" and "min" are minor. Any other
+/// unique start of a name works too, so "mixo" is Mixolydian, but "mi" is
+/// ambiguous.
+impl TryFrom<&str> for ScaleMode {
+    type Error = ScaleParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        match value {
+            "M" => return Ok(ScaleMode::Ionian),
+            "m" => return Ok(ScaleMode::Aeolian),
+            _ => {}
+        }
+
+        let name = value
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        match name.as_str() {
+            "" => return Err(ScaleParseError::InvalidMode),
+            "maj" => return Ok(ScaleMode::Ionian),
+            "min" => return Ok(ScaleMode::Aeolian),
+            _ => {}
+        }
+
+        if let Some((_, mode)) = SCALE_MODE_NAMES
+            .iter()
+            .find(|(full_name, _)| *full_name == name)
+        {
+            return Ok(*mode);
+        }
+
+        let mut matching_modes = SCALE_MODE_NAMES
+            .iter()
+            .filter(|(full_name, _)| full_name.starts_with(&name))
+            .map(|(_, mode)| *mode);
+
+        match matching_modes.next() {
+            None => Err(ScaleParseError::InvalidMode),
+            Some(mode) if matching_modes.all(|other| other == mode) => Ok(mode),
+            Some(_) => Err(ScaleParseError::AmbiguousMode),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +538,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mode_gives_spelled_note_at_degree() {
+        let mode = ScaleMode::Ionian;
+        let root = AbstractNote::try_from("F").unwrap();
+        let notes: Vec<AbstractNote> = [
+            ScaleDegree::First,
+            ScaleDegree::Second,
+            ScaleDegree::Third,
+            ScaleDegree::Fourth,
+            ScaleDegree::Fifth,
+            ScaleDegree::Sixth,
+            ScaleDegree::Seventh,
+            ScaleDegree::Octave,
+        ]
+        .into_iter()
+        .map(|degree| mode.spelled_note_at_degree(root, degree))
+        .collect();
+        assert_eq!(
+            notes,
+            ["F", "G", "A", "Bb", "C", "D", "E", "F"]
+                .into_iter()
+                .map(|n| AbstractNote::try_from(n).unwrap())
+                .collect::<Vec<AbstractNote>>()
+        );
+
+        let root = AbstractNote::try_from("B#").unwrap();
+        assert_eq!(
+            mode.spelled_note_at_degree(root, ScaleDegree::Seventh),
+            AbstractNote::try_from("A##").unwrap()
+        );
+
+        let mode = ScaleMode::Aeolian;
+        let root = AbstractNote::try_from("C").unwrap();
+        assert_eq!(
+            mode.spelled_note_at_degree(root, ScaleDegree::Third),
+            AbstractNote::try_from("Eb").unwrap()
+        );
+        assert_eq!(
+            mode.spelled_note_at_degree(root, ScaleDegree::Sixth),
+            AbstractNote::try_from("Ab").unwrap()
+        );
+    }
+
     #[test]
     fn assert_ionian_intervals() {
         assert_eq!(
@@ -486,4 +832,129 @@ mod tests {
             SimpleInterval::PerfectOctave
         );
     }
+
+    #[test]
+    fn assert_harmonic_minor_intervals() {
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::First),
+            SimpleInterval::PerfectUnison
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Second),
+            SimpleInterval::MajorSecond
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Third),
+            SimpleInterval::MinorThird
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Fourth),
+            SimpleInterval::PerfectFourth
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Fifth),
+            SimpleInterval::PerfectFifth
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Sixth),
+            SimpleInterval::MinorSixth
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Seventh),
+            SimpleInterval::MajorSeventh
+        );
+        assert_eq!(
+            harmonic_minor_intervals(ScaleDegree::Octave),
+            SimpleInterval::PerfectOctave
+        );
+    }
+
+    #[test]
+    fn parses_mode_names() {
+        assert_eq!(ScaleMode::try_from("Major"), Ok(ScaleMode::Ionian));
+        assert_eq!(ScaleMode::try_from("IONIAN"), Ok(ScaleMode::Ionian));
+        assert_eq!(ScaleMode::try_from("M"), Ok(ScaleMode::Ionian));
+        assert_eq!(ScaleMode::try_from("maj"), Ok(ScaleMode::Ionian));
+        assert_eq!(ScaleMode::try_from("m"), Ok(ScaleMode::Aeolian));
+        assert_eq!(ScaleMode::try_from("min"), Ok(ScaleMode::Aeolian));
+        assert_eq!(
+            ScaleMode::try_from("Natural  Minor"),
+            Ok(ScaleMode::Aeolian)
+        );
+        assert_eq!(ScaleMode::try_from("dorian"), Ok(ScaleMode::Dorian));
+        assert_eq!(ScaleMode::try_from("mixo"), Ok(ScaleMode::Mixolydian));
+        assert_eq!(
+            ScaleMode::try_from("harmonic minor"),
+            Ok(ScaleMode::HarmonicMinor)
+        );
+        assert_eq!(ScaleMode::try_from("harm"), Ok(ScaleMode::HarmonicMinor));
+        assert_eq!(ScaleMode::try_from("Lydian"), Ok(ScaleMode::Lydian));
+        assert_eq!(
+            ScaleMode::try_from("lydian dom"),
+            Ok(ScaleMode::LydianDominant)
+        );
+        assert_eq!(
+            ScaleMode::try_from("Acoustic"),
+            Ok(ScaleMode::LydianDominant)
+        );
+        assert_eq!(
+            ScaleMode::try_from("Minor Pentatonic"),
+            Ok(ScaleMode::PentatonicMinor)
+        );
+        assert_eq!(
+            ScaleMode::try_from("pentatonic maj"),
+            Ok(ScaleMode::PentatonicMajor)
+        );
+        assert_eq!(ScaleMode::try_from("Blues"), Ok(ScaleMode::Blues));
+
+        assert_eq!(
+            ScaleMode::try_from("mi"),
+            Err(ScaleParseError::AmbiguousMode)
+        );
+        assert_eq!(
+            ScaleMode::try_from("l"),
+            Err(ScaleParseError::AmbiguousMode)
+        );
+        assert_eq!(
+            ScaleMode::try_from("bebop"),
+            Err(ScaleParseError::InvalidMode)
+        );
+        assert_eq!(ScaleMode::try_from(""), Err(ScaleParseError::InvalidMode));
+    }
+
+    #[test]
+    fn assert_lydian_dominant_intervals() {
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::First),
+            SimpleInterval::PerfectUnison
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Second),
+            SimpleInterval::MajorSecond
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Third),
+            SimpleInterval::MajorThird
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Fourth),
+            SimpleInterval::AugmentedFourth
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Fifth),
+            SimpleInterval::PerfectFifth
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Sixth),
+            SimpleInterval::MajorSixth
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Seventh),
+            SimpleInterval::MinorSeventh
+        );
+        assert_eq!(
+            lydian_dominant_intervals(ScaleDegree::Octave),
+            SimpleInterval::PerfectOctave
+        );
+    }
 }
@@ -1,22 +1,24 @@
-use strum::IntoEnumIterator;
+use crate::{AbstractNote, SimpleInterval};
 
-use crate::AbstractNote;
-
-use super::{ScaleDegree, ScaleDegreeIter, ScaleMode};
+use super::ScaleMode;
 
 #[derive(Debug)]
 pub struct ScaleNoteIter {
     root: AbstractNote,
-    mode: ScaleMode,
-    current_degree: ScaleDegreeIter,
+    intervals: std::vec::IntoIter<SimpleInterval>,
 }
 
 impl ScaleNoteIter {
     pub fn new(root: AbstractNote, mode: ScaleMode) -> Self {
         Self {
             root,
-            mode,
-            current_degree: ScaleDegree::iter(),
+            // Walk up to and including the octave.
+            intervals: mode
+                .intervals()
+                .into_iter()
+                .chain(std::iter::once(SimpleInterval::PerfectOctave))
+                .collect::<Vec<_>>()
+                .into_iter(),
         }
     }
 }
@@ -25,9 +27,8 @@ impl Iterator for ScaleNoteIter {
     type Item = AbstractNote;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next_degree = self.current_degree.next()?;
-        let next_note = self.root + self.mode.interval_at_degree(next_degree);
-        Some(next_note)
+        let next_interval = self.intervals.next()?;
+        Some(self.root.spelled_add_interval(next_interval))
     }
 }
 